@@ -0,0 +1,110 @@
+//! Compares `List`'s ring buffer storage with the `Vec` layout it replaced.
+//!
+//! Run with `cargo bench --bench deque`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rusty_list::{List, ListItem};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn report(name: &str, size: usize, vec: Duration, list: Duration) {
+    println!(
+        "{name:<20} n={size:<8} Vec: {:>12.3?}  List: {:>12.3?}  ({:.1}x)",
+        vec,
+        list,
+        vec.as_secs_f64() / list.as_secs_f64().max(f64::EPSILON)
+    );
+}
+
+fn push_front(size: usize) {
+    let vec = time(|| {
+        let mut items: Vec<ListItem> = Vec::new();
+        for i in 0..size {
+            items.insert(0, ListItem::Int(black_box(i as i32)));
+        }
+        black_box(&items);
+    });
+    let list = time(|| {
+        let mut list = List::new();
+        for i in 0..size {
+            list.insert_at_beginning(black_box(i as i32));
+        }
+        black_box(&list);
+    });
+    report("insert_at_beginning", size, vec, list);
+}
+
+fn pop_front(size: usize) {
+    let mut items: Vec<ListItem> = (0..size as i32).map(ListItem::Int).collect();
+    let vec = time(|| {
+        while !items.is_empty() {
+            black_box(items.remove(0));
+        }
+    });
+    let mut list: List = (0..size as i32).collect();
+    let list = time(|| {
+        while let Some(item) = list.pop_front() {
+            black_box(item);
+        }
+    });
+    report("pop_front", size, vec, list);
+}
+
+fn push_back(size: usize) {
+    let vec = time(|| {
+        let mut items: Vec<ListItem> = Vec::new();
+        for i in 0..size {
+            items.push(ListItem::Int(black_box(i as i32)));
+        }
+        black_box(&items);
+    });
+    let list = time(|| {
+        let mut list = List::new();
+        for i in 0..size {
+            list.push_back(black_box(i as i32));
+        }
+        black_box(&list);
+    });
+    report("push_back", size, vec, list);
+}
+
+fn get(size: usize) {
+    let items: Vec<ListItem> = (0..size as i32).map(ListItem::Int).collect();
+    let vec = time(|| {
+        for i in 0..size {
+            if let ListItem::Int(value) = &items[black_box(i)] {
+                black_box(value);
+            }
+        }
+    });
+    // Start from the front so the ring buffer wraps around.
+    let mut list = List::new();
+    for i in (0..size as i32).rev() {
+        list.insert_at_beginning(i);
+    }
+    let list = time(|| {
+        for i in 0..size {
+            if let ListItem::Int(value) = &list[black_box(i)] {
+                black_box(value);
+            }
+        }
+    });
+    report("index", size, vec, list);
+}
+
+fn main() {
+    for size in SIZES {
+        push_front(size);
+        pop_front(size);
+        push_back(size);
+        get(size);
+    }
+}
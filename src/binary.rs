@@ -0,0 +1,385 @@
+use std::io::{self, Read, Write};
+
+use crate::parse::{check_depth, MAX_DEPTH};
+use crate::{List, ListError, ListItem, ListMap};
+
+/// The first four bytes of every encoded list.
+const MAGIC: [u8; 4] = *b"RLST";
+/// The version of the format written by `List::encode`.
+const VERSION: u8 = 1;
+
+// Type tags, see docs/binary-format.md.
+const TAG_NULL: u8 = 0x00;
+const TAG_BOOL: u8 = 0x01;
+const TAG_INT: u8 = 0x02;
+const TAG_I8: u8 = 0x03;
+const TAG_I16: u8 = 0x04;
+const TAG_I64: u8 = 0x05;
+const TAG_I128: u8 = 0x06;
+const TAG_ISIZE: u8 = 0x07;
+const TAG_U8: u8 = 0x08;
+const TAG_U16: u8 = 0x09;
+const TAG_U32: u8 = 0x0A;
+const TAG_U64: u8 = 0x0B;
+const TAG_U128: u8 = 0x0C;
+const TAG_USIZE: u8 = 0x0D;
+const TAG_FLOAT: u8 = 0x0E;
+const TAG_CHAR: u8 = 0x0F;
+const TAG_STR: u8 = 0x10;
+const TAG_LIST: u8 = 0x11;
+const TAG_MAP: u8 = 0x12;
+
+impl List {
+    /// Writes the list in the versioned binary format described in
+    /// `docs/binary-format.md`.
+    ///
+    /// Every item keeps its exact variant, and floats keep their exact bits,
+    /// so `List::decode` returns a list equal to this one. The whole encoding
+    /// is built in memory and handed to the writer with a single `write_all`.
+    ///
+    /// Returns `ListError::Unsupported` if the list contains a custom value
+    /// and `ListError::TooDeep` if lists and maps nest more than 128 levels
+    /// deep, the top-level list included, in which case nothing is written,
+    /// and `ListError::Io` if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let mut bytes = Vec::new();
+    /// list![1, -1, "a"].encode(&mut bytes).unwrap();
+    /// assert_eq!(&bytes[..5], b"RLST\x01");
+    /// assert_eq!(&bytes[5..13], [0x03, 0x02, 0x02, 0x02, 0x01, 0x10, 0x01, b'a']);
+    /// assert_eq!(bytes.len(), 17);
+    /// ```
+    pub fn encode(&self, writer: &mut impl Write) -> Result<(), ListError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        encode_list(&mut bytes, self, 0)?;
+        let checksum = crc32(CRC_INIT, &bytes) ^ CRC_INIT;
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Reads a list written by `List::encode`.
+    ///
+    /// Reading stops right after the checksum, so several lists can be
+    /// decoded one after another from the same reader. The reader is read in
+    /// small pieces, so wrap unbuffered readers such as files and sockets in a
+    /// `BufReader`.
+    ///
+    /// Returns `ListError::Decode` with the byte offset of the problem if the
+    /// input is truncated, has the wrong magic number or an unsupported
+    /// version, holds malformed values, nests more than 128 levels deep or
+    /// fails the checksum, and `ListError::Io` if reading fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, List, ListError};
+    ///
+    /// let list = list![1, 1.0, 'c', "text", (), list![u128::MAX]];
+    /// let mut bytes = Vec::new();
+    /// list.encode(&mut bytes).unwrap();
+    /// assert_eq!(List::decode(&mut bytes.as_slice()), Ok(list));
+    ///
+    /// bytes[6] ^= 1;
+    /// assert!(matches!(
+    ///     List::decode(&mut bytes.as_slice()),
+    ///     Err(ListError::Decode { .. })
+    /// ));
+    /// ```
+    pub fn decode(reader: &mut impl Read) -> Result<List, ListError> {
+        let mut decoder = Decoder {
+            reader,
+            offset: 0,
+            crc: CRC_INIT,
+        };
+        let mut magic = [0; 4];
+        decoder.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(decoder.error_at(0, "bad magic number"));
+        }
+        if decoder.byte()? != VERSION {
+            return Err(decoder.error_at(4, "unsupported version"));
+        }
+        let list = decoder.list(0)?;
+
+        let expected = decoder.crc ^ CRC_INIT;
+        let offset = decoder.offset;
+        let mut checksum = [0; 4];
+        decoder.read_exact(&mut checksum)?;
+        if u32::from_le_bytes(checksum) != expected {
+            return Err(decoder.error_at(offset, "checksum mismatch"));
+        }
+        Ok(list)
+    }
+}
+
+fn encode_list(out: &mut Vec<u8>, list: &List, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    write_varint(out, list.len() as u128);
+    for item in list {
+        encode_item(out, item, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn encode_map(out: &mut Vec<u8>, map: &ListMap, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    write_varint(out, map.len() as u128);
+    for (key, value) in map.iter() {
+        write_str(out, key);
+        encode_item(out, value, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn encode_item(out: &mut Vec<u8>, item: &ListItem, depth: usize) -> Result<(), ListError> {
+    match *item {
+        ListItem::Null => out.push(TAG_NULL),
+        ListItem::Bool(value) => out.extend_from_slice(&[TAG_BOOL, u8::from(value)]),
+        ListItem::Int(value) => write_signed(out, TAG_INT, value.into()),
+        ListItem::I8(value) => write_signed(out, TAG_I8, value.into()),
+        ListItem::I16(value) => write_signed(out, TAG_I16, value.into()),
+        ListItem::I64(value) => write_signed(out, TAG_I64, value.into()),
+        ListItem::I128(value) => write_signed(out, TAG_I128, value),
+        ListItem::Isize(value) => write_signed(out, TAG_ISIZE, value as i128),
+        ListItem::U8(value) => write_unsigned(out, TAG_U8, value.into()),
+        ListItem::U16(value) => write_unsigned(out, TAG_U16, value.into()),
+        ListItem::U32(value) => write_unsigned(out, TAG_U32, value.into()),
+        ListItem::U64(value) => write_unsigned(out, TAG_U64, value.into()),
+        ListItem::U128(value) => write_unsigned(out, TAG_U128, value),
+        ListItem::Usize(value) => write_unsigned(out, TAG_USIZE, value as u128),
+        ListItem::Float(value) => {
+            out.push(TAG_FLOAT);
+            out.extend_from_slice(&value.to_bits().to_le_bytes());
+        }
+        ListItem::Char(value) => write_unsigned(out, TAG_CHAR, u32::from(value).into()),
+        ListItem::Str(ref value) => {
+            out.push(TAG_STR);
+            write_str(out, value);
+        }
+        ListItem::List(ref value) => {
+            out.push(TAG_LIST);
+            encode_list(out, value, depth)?;
+        }
+        ListItem::Map(ref value) => {
+            out.push(TAG_MAP);
+            encode_map(out, value, depth)?;
+        }
+        ListItem::Custom(_) => {
+            return Err(ListError::Unsupported {
+                type_name: item.type_name(),
+                operation: "binary encoding",
+            })
+        }
+    }
+    Ok(())
+}
+
+/// Fails if a list or map at `depth` would be too deep for `List::decode`.
+fn write_signed(out: &mut Vec<u8>, tag: u8, value: i128) {
+    // Zigzag encoding maps 0, -1, 1, -2, ... to 0, 1, 2, 3, ...
+    write_unsigned(out, tag, ((value << 1) ^ (value >> 127)) as u128);
+}
+
+fn write_unsigned(out: &mut Vec<u8>, tag: u8, value: u128) {
+    out.push(tag);
+    write_varint(out, value);
+}
+
+fn write_str(out: &mut Vec<u8>, value: &str) {
+    write_varint(out, value.len() as u128);
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Writes an unsigned LEB128 varint: 7 bits per byte, least significant
+/// group first, with the high bit set on every byte but the last.
+fn write_varint(out: &mut Vec<u8>, mut value: u128) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads the binary format while tracking the offset and the running checksum.
+struct Decoder<'a, R> {
+    reader: &'a mut R,
+    offset: usize,
+    crc: u32,
+}
+
+impl<R: Read> Decoder<'_, R> {
+    fn error_at(&self, offset: usize, reason: &'static str) -> ListError {
+        ListError::Decode { offset, reason }
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ListError> {
+        match self.reader.read_exact(buf) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(self.error_at(self.offset, "unexpected end of input"));
+            }
+            Err(error) => return Err(error.into()),
+        }
+        self.crc = crc32(self.crc, buf);
+        self.offset += buf.len();
+        Ok(())
+    }
+
+    fn byte(&mut self) -> Result<u8, ListError> {
+        let mut buf = [0];
+        self.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+
+    fn varint(&mut self) -> Result<u128, ListError> {
+        let start = self.offset;
+        let mut value: u128 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            let bits = u128::from(byte & 0x7F);
+            if shift >= 128 || (bits << shift) >> shift != bits {
+                return Err(self.error_at(start, "varint overflows 128 bits"));
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads a varint and converts it to `T`.
+    fn unsigned<T: TryFrom<u128>>(&mut self) -> Result<T, ListError> {
+        let start = self.offset;
+        let value = self.varint()?;
+        T::try_from(value).map_err(|_| self.error_at(start, "integer out of range"))
+    }
+
+    /// Reads a zigzag varint and converts it to `T`.
+    fn signed<T: TryFrom<i128>>(&mut self) -> Result<T, ListError> {
+        let start = self.offset;
+        let value = self.varint()?;
+        let value = (value >> 1) as i128 ^ -((value & 1) as i128);
+        T::try_from(value).map_err(|_| self.error_at(start, "integer out of range"))
+    }
+
+    fn string(&mut self) -> Result<String, ListError> {
+        let len: usize = self.unsigned()?;
+        let start = self.offset;
+        // Read in bounded chunks so that a corrupt length cannot make us
+        // allocate more memory than the input actually holds.
+        let mut bytes = Vec::new();
+        let mut chunk = [0; 4096];
+        while bytes.len() < len {
+            let n = chunk.len().min(len - bytes.len());
+            self.read_exact(&mut chunk[..n])?;
+            bytes.extend_from_slice(&chunk[..n]);
+        }
+        String::from_utf8(bytes).map_err(|_| self.error_at(start, "invalid UTF-8"))
+    }
+
+    fn list(&mut self, depth: usize) -> Result<List, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error_at(self.offset, "nesting too deep"));
+        }
+        let len: usize = self.unsigned()?;
+        let mut list = List::new();
+        for _ in 0..len {
+            list.insert(self.item(depth + 1)?);
+        }
+        Ok(list)
+    }
+
+    fn map(&mut self, depth: usize) -> Result<ListMap, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error_at(self.offset, "nesting too deep"));
+        }
+        let len: usize = self.unsigned()?;
+        let mut map = ListMap::new();
+        for _ in 0..len {
+            let key = self.string()?;
+            map.insert(key, self.item(depth + 1)?);
+        }
+        Ok(map)
+    }
+
+    fn item(&mut self, depth: usize) -> Result<ListItem, ListError> {
+        let start = self.offset;
+        let item = match self.byte()? {
+            TAG_NULL => ListItem::Null,
+            TAG_BOOL => match self.byte()? {
+                0 => ListItem::Bool(false),
+                1 => ListItem::Bool(true),
+                _ => return Err(self.error_at(start, "invalid bool")),
+            },
+            TAG_INT => ListItem::Int(self.signed()?),
+            TAG_I8 => ListItem::I8(self.signed()?),
+            TAG_I16 => ListItem::I16(self.signed()?),
+            TAG_I64 => ListItem::I64(self.signed()?),
+            TAG_I128 => ListItem::I128(self.signed()?),
+            TAG_ISIZE => ListItem::Isize(self.signed()?),
+            TAG_U8 => ListItem::U8(self.unsigned()?),
+            TAG_U16 => ListItem::U16(self.unsigned()?),
+            TAG_U32 => ListItem::U32(self.unsigned()?),
+            TAG_U64 => ListItem::U64(self.unsigned()?),
+            TAG_U128 => ListItem::U128(self.unsigned()?),
+            TAG_USIZE => ListItem::Usize(self.unsigned()?),
+            TAG_FLOAT => {
+                let mut bits = [0; 8];
+                self.read_exact(&mut bits)?;
+                ListItem::Float(f64::from_bits(u64::from_le_bytes(bits)))
+            }
+            TAG_CHAR => {
+                let value: u32 = self.unsigned()?;
+                let value = char::from_u32(value).ok_or(self.error_at(start, "invalid char"))?;
+                ListItem::Char(value)
+            }
+            TAG_STR => ListItem::Str(self.string()?),
+            TAG_LIST => ListItem::List(self.list(depth)?),
+            TAG_MAP => ListItem::Map(self.map(depth)?),
+            _ => return Err(self.error_at(start, "unknown type tag")),
+        };
+        Ok(item)
+    }
+}
+
+/// The initial and final XOR value of CRC-32.
+const CRC_INIT: u32 = 0xFFFF_FFFF;
+
+/// Lookup table for CRC-32 (IEEE 802.3, reflected polynomial `0xEDB88320`),
+/// the checksum used by zlib, gzip and PNG.
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Feeds bytes into a running CRC-32 state.
+fn crc32(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        crc = CRC_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc
+}
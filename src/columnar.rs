@@ -0,0 +1,522 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::{null_mut, List, ListItem, ListItemKind, ListMap, ListValue};
+
+/// The column an item is stored in, named after the `ListItem` variant it holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Int,
+    I8,
+    I16,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    Float,
+    Bool,
+    Char,
+    Str,
+    Null,
+    Other,
+}
+
+/// The position of an item: its column and its offset within that column.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    column: Column,
+    offset: u32,
+}
+
+/// A list with the same `insert`, `get` and `iter` API as `List`, storing each
+/// type in its own dense column.
+///
+/// `List` spends a full `ListItem` (32 bytes on 64-bit targets) on every
+/// item. `ColumnarList` keeps every integer width, `f64`, `bool`, `char` and
+/// `String` values in typed vectors, plus an 8-byte slot per item recording
+/// its column and offset, so a `u8` costs 9 bytes, an `i32` 12 and an `i128`
+/// 24, and scanning one type with `ColumnarList::column` walks a contiguous
+/// slice. `Null` takes no column space, and nested lists, maps and custom
+/// values live in a shared column of `ListItem`s.
+///
+/// Each column holds at most `u32::MAX` items.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::ColumnarList;
+///
+/// let mut list = ColumnarList::new();
+/// list.insert(1);
+/// list.insert(2.5);
+/// list.insert("three");
+/// list.insert(4);
+/// list.insert(5u8);
+/// assert_eq!(list.get::<f64>(1), Some(&2.5));
+/// assert_eq!(list.column::<i32>(), Some(&[1, 4][..]));
+/// assert_eq!(list.column::<u8>(), Some(&[5][..]));
+/// assert_eq!(list.to_string(), "[1, 2.5, three, 4, 5]");
+/// ```
+#[derive(Clone, Default)]
+pub struct ColumnarList {
+    slots: Vec<Slot>,
+    ints: Vec<i32>,
+    i8s: Vec<i8>,
+    i16s: Vec<i16>,
+    i64s: Vec<i64>,
+    i128s: Vec<i128>,
+    isizes: Vec<isize>,
+    u8s: Vec<u8>,
+    u16s: Vec<u16>,
+    u32s: Vec<u32>,
+    u64s: Vec<u64>,
+    u128s: Vec<u128>,
+    usizes: Vec<usize>,
+    floats: Vec<f64>,
+    bools: Vec<bool>,
+    chars: Vec<char>,
+    strings: Vec<String>,
+    others: Vec<ListItem>,
+}
+
+/// A borrowed view of an item, as yielded by `ColumnarList::iter`.
+///
+/// Each variant borrows the value of the `ListItem` variant of the same name,
+/// so iterating a `ColumnarList` reads its columns in place instead of cloning
+/// strings and nested lists. Views compare like the items they borrow, and
+/// `ListItem::from` turns one into an owned item.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{ListItem, ListItemRef};
+///
+/// let item = ListItem::from("a");
+/// let view = ListItemRef::from(&item);
+/// assert_eq!(view, ListItemRef::Str(&"a".to_string()));
+/// assert_eq!(view.as_any().downcast_ref::<String>(), Some(&"a".to_string()));
+/// assert_eq!(ListItem::from(view), item);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub enum ListItemRef<'a> {
+    Int(&'a i32),
+    I8(&'a i8),
+    I16(&'a i16),
+    I64(&'a i64),
+    I128(&'a i128),
+    Isize(&'a isize),
+    U8(&'a u8),
+    U16(&'a u16),
+    U32(&'a u32),
+    U64(&'a u64),
+    U128(&'a u128),
+    Usize(&'a usize),
+    Str(&'a String),
+    Float(&'a f64),
+    Bool(&'a bool),
+    Char(&'a char),
+    Null,
+    List(&'a List),
+    Map(&'a ListMap),
+    Custom(&'a dyn ListValue),
+}
+
+/// Generates the conversions and comparisons of `ListItemRef` for the
+/// variants that hold a plain value. `Float`, `Null` and `Custom` are handled
+/// by hand.
+macro_rules! impl_item_ref {
+    ($($variant:ident),* $(,)?) => {
+        impl<'a> ListItemRef<'a> {
+            /// Returns the kind of the borrowed item.
+            pub fn kind(self) -> ListItemKind {
+                match self {
+                    $(ListItemRef::$variant(_) => ListItemKind::$variant,)*
+                    ListItemRef::Float(_) => ListItemKind::Float,
+                    ListItemRef::Null => ListItemKind::Null,
+                    ListItemRef::Custom(_) => ListItemKind::Custom,
+                }
+            }
+
+            /// Returns the borrowed value, to be downcast to its type.
+            pub fn as_any(self) -> &'a dyn Any {
+                match self {
+                    $(ListItemRef::$variant(value) => value,)*
+                    ListItemRef::Float(value) => value,
+                    ListItemRef::Null => &(),
+                    ListItemRef::Custom(value) => value,
+                }
+            }
+        }
+
+        impl<'a> From<&'a ListItem> for ListItemRef<'a> {
+            fn from(item: &'a ListItem) -> Self {
+                match item {
+                    $(ListItem::$variant(value) => ListItemRef::$variant(value),)*
+                    ListItem::Float(value) => ListItemRef::Float(value),
+                    ListItem::Null => ListItemRef::Null,
+                    ListItem::Custom(value) => ListItemRef::Custom(value.as_ref()),
+                }
+            }
+        }
+
+        impl From<ListItemRef<'_>> for ListItem {
+            fn from(item: ListItemRef<'_>) -> Self {
+                match item {
+                    $(ListItemRef::$variant(value) => ListItem::$variant(value.clone()),)*
+                    ListItemRef::Float(value) => ListItem::Float(*value),
+                    ListItemRef::Null => ListItem::Null,
+                    ListItemRef::Custom(value) => ListItem::Custom(value.clone_value()),
+                }
+            }
+        }
+
+        impl PartialEq for ListItemRef<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                match (*self, *other) {
+                    $((ListItemRef::$variant(a), ListItemRef::$variant(b)) => a == b,)*
+                    (ListItemRef::Float(a), ListItemRef::Float(b)) => a.to_bits() == b.to_bits(),
+                    (ListItemRef::Null, ListItemRef::Null) => true,
+                    (ListItemRef::Custom(a), ListItemRef::Custom(b)) => a.eq_value(b),
+                    _ => false,
+                }
+            }
+        }
+
+        // Implementation of Display for ListItemRef
+        impl fmt::Display for ListItemRef<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(ListItemRef::$variant(value) => write!(f, "{value}"),)*
+                    ListItemRef::Float(value) => write!(f, "{value}"),
+                    ListItemRef::Null => write!(f, "null"),
+                    ListItemRef::Custom(value) => write!(f, "{value}"),
+                }
+            }
+        }
+    };
+}
+
+impl_item_ref!(
+    Int, I8, I16, I64, I128, Isize, U8, U16, U32, U64, U128, Usize, Str, Bool, Char, List, Map,
+);
+
+impl Eq for ListItemRef<'_> {}
+
+/// Pushes a value onto a column and returns its offset.
+fn push<T>(column: &mut Vec<T>, value: T) -> u32 {
+    let offset = u32::try_from(column.len()).expect("a column holds at most u32::MAX items");
+    column.push(value);
+    offset
+}
+
+/// Generates the conversions between `ListItem`s and the typed columns, one
+/// `Variant => field` pair per column.
+macro_rules! impl_columns {
+    ($($variant:ident => $field:ident),* $(,)?) => {
+        impl ColumnarList {
+            /// Moves an item into its column and returns its slot.
+            fn store(&mut self, item: ListItem) -> Slot {
+                let (column, offset) = match item {
+                    $(ListItem::$variant(value) => (Column::$variant, push(&mut self.$field, value)),)*
+                    ListItem::Null => (Column::Null, 0),
+                    item => (Column::Other, push(&mut self.others, item)),
+                };
+                Slot { column, offset }
+            }
+
+            fn value_mut(&mut self, slot: Slot) -> &mut dyn Any {
+                let offset = slot.offset as usize;
+                match slot.column {
+                    $(Column::$variant => &mut self.$field[offset],)*
+                    Column::Null => null_mut(),
+                    Column::Other => self.others[offset].as_any_mut(),
+                }
+            }
+
+            fn columns(&self) -> [&dyn Any; 16] {
+                [$(&self.$field,)*]
+            }
+
+            fn item(&self, slot: Slot) -> ListItemRef<'_> {
+                let offset = slot.offset as usize;
+                match slot.column {
+                    $(Column::$variant => ListItemRef::$variant(&self.$field[offset]),)*
+                    Column::Null => ListItemRef::Null,
+                    Column::Other => ListItemRef::from(&self.others[offset]),
+                }
+            }
+        }
+    };
+}
+
+impl_columns!(
+    Int => ints,
+    I8 => i8s,
+    I16 => i16s,
+    I64 => i64s,
+    I128 => i128s,
+    Isize => isizes,
+    U8 => u8s,
+    U16 => u16s,
+    U32 => u32s,
+    U64 => u64s,
+    U128 => u128s,
+    Usize => usizes,
+    Float => floats,
+    Bool => bools,
+    Char => chars,
+    Str => strings,
+);
+
+impl ColumnarList {
+    /// Creates a new, empty `ColumnarList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::ColumnarList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value at the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value's column already holds `u32::MAX` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::ColumnarList::new();
+    /// list.insert(42);
+    /// assert_eq!(list.get::<i32>(0), Some(&42));
+    /// ```
+    pub fn insert<T: Into<ListItem>>(&mut self, value: T) {
+        let slot = self.store(value.into());
+        self.slots.push(slot);
+    }
+
+    /// Retrieves a reference to the item at the specified index if the type matches.
+    ///
+    /// Returns `None` if the index is out of bounds or the type doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::ColumnarList::new();
+    /// list.insert("a".to_string());
+    /// list.insert(7u8);
+    /// assert_eq!(list.get::<String>(0), Some(&"a".to_string()));
+    /// assert_eq!(list.get::<u8>(1), Some(&7));
+    /// assert_eq!(list.get::<i32>(1), None);
+    /// ```
+    pub fn get<T: 'static>(&self, index: usize) -> Option<&T> {
+        let slot = *self.slots.get(index)?;
+        self.item(slot).as_any().downcast_ref::<T>()
+    }
+
+    /// Retrieves a mutable reference to the item at the specified index if the type matches.
+    ///
+    /// Returns `None` if the index is out of bounds or the type doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::ColumnarList::new();
+    /// list.insert(1.5);
+    /// *list.get_mut::<f64>(0).unwrap() *= 2.0;
+    /// assert_eq!(list.get::<f64>(0), Some(&3.0));
+    ///
+    /// list.insert(());
+    /// assert!(list.get_mut::<()>(1).is_some());
+    /// ```
+    pub fn get_mut<T: 'static>(&mut self, index: usize) -> Option<&mut T> {
+        let slot = *self.slots.get(index)?;
+        self.value_mut(slot).downcast_mut::<T>()
+    }
+
+    /// Returns all values of type `T` in insertion order as one contiguous slice.
+    ///
+    /// Returns `None` if `T` does not have a dedicated column, that is for
+    /// anything but the primitive integers, `f64`, `bool`, `char` and `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::ColumnarList::new();
+    /// list.insert(1.0);
+    /// list.insert("skipped");
+    /// list.insert(2.0);
+    /// assert_eq!(list.column::<f64>().unwrap().iter().sum::<f64>(), 3.0);
+    /// assert_eq!(list.column::<u8>(), Some(&[][..]));
+    /// assert_eq!(list.column::<()>(), None);
+    /// ```
+    pub fn column<T: 'static>(&self) -> Option<&[T]> {
+        self.columns()
+            .into_iter()
+            .find_map(|column| column.downcast_ref::<Vec<T>>())
+            .map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the items in the list.
+    ///
+    /// Items are borrowed from their columns as `ListItemRef`s, without
+    /// cloning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{ColumnarList, ListItemRef};
+    ///
+    /// let mut list = ColumnarList::new();
+    /// list.insert(42);
+    /// list.insert("a");
+    /// let items: Vec<ListItemRef> = list.iter().collect();
+    /// assert_eq!(items, [ListItemRef::Int(&42), ListItemRef::Str(&"a".to_string())]);
+    /// ```
+    pub fn iter(&self) -> ColumnarIter<'_> {
+        ColumnarIter {
+            list: self,
+            index: 0,
+            end: self.slots.len(),
+        }
+    }
+
+    /// Returns the number of items in the list.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Clears the list, removing all items.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Copies the items into a `List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ColumnarList};
+    ///
+    /// let columnar: ColumnarList = list![1, "a", ()].into_iter().collect();
+    /// assert_eq!(columnar.to_list(), list![1, "a", ()]);
+    /// ```
+    pub fn to_list(&self) -> List {
+        self.iter().collect()
+    }
+}
+
+impl<T: Into<ListItem>> FromIterator<T> for ColumnarList {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = ColumnarList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Into<ListItem>> Extend<T> for ColumnarList {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl From<List> for ColumnarList {
+    fn from(list: List) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl PartialEq for ColumnarList {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .slots
+                .iter()
+                .zip(&other.slots)
+                .all(|(&a, &b)| a.column == b.column && self.item(a) == other.item(b))
+    }
+}
+
+// Implementation of Debug for ColumnarList
+impl fmt::Debug for ColumnarList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Implementation of Display for ColumnarList
+impl fmt::Display for ColumnarList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{item}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+// Iterator for ColumnarList
+pub struct ColumnarIter<'a> {
+    list: &'a ColumnarList,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for ColumnarIter<'a> {
+    type Item = ListItemRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            let item = self.list.item(self.list.slots[self.index]);
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ColumnarIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(self.list.item(self.list.slots[self.end]))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for ColumnarIter<'_> {}
+
+impl<'a> IntoIterator for &'a ColumnarList {
+    type Item = ListItemRef<'a>;
+    type IntoIter = ColumnarIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
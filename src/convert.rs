@@ -0,0 +1,199 @@
+use crate::{List, ListError, ListItem};
+
+/// How far `List::get_as_with` may go to convert an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Coercion {
+    /// Only conversions that preserve the value exactly: between integer
+    /// widths when the value fits, between integers and `Float` when the value
+    /// is representable in both, and from `Char` to `String`.
+    #[default]
+    Strict,
+    /// Everything `Strict` allows, plus lossy and textual conversions:
+    /// `Float` to integers truncates towards zero, integers to `f64` round,
+    /// `Bool` converts to `0`/`1` and back, strings are parsed into numbers,
+    /// booleans and single characters, and numbers, booleans and characters
+    /// are formatted into `String`s.
+    Lenient,
+}
+
+/// A type that can be converted from a `ListItem` under a `Coercion` mode.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{Coercion, ListItem, TryFromListItem};
+///
+/// let item = ListItem::Str(" 42 ".to_string());
+/// assert!(i64::try_from_item(&item, Coercion::Strict).is_err());
+/// assert_eq!(i64::try_from_item(&item, Coercion::Lenient), Ok(42));
+/// ```
+pub trait TryFromListItem: Sized {
+    /// Converts the item, returning `ListError::Conversion` if `mode` does not allow it.
+    fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError>;
+}
+
+fn conversion_error<T>(item: &ListItem) -> ListError {
+    ListError::Conversion {
+        from: item.type_name(),
+        to: std::any::type_name::<T>(),
+    }
+}
+
+/// Converts a float to an integer type, truncating only if `truncate` is set.
+fn float_to_int<T>(value: f64, truncate: bool) -> Option<T>
+where
+    T: TryFrom<i128> + TryFrom<u128>,
+{
+    let value = if truncate { value.trunc() } else { value };
+    if value.is_nan() || value.fract() != 0.0 {
+        return None;
+    }
+    // 2^127 and 2^128 are the first floats out of range of `i128` and `u128`.
+    if value < 0.0 {
+        if value < i128::MIN as f64 {
+            return None;
+        }
+        T::try_from(value as i128).ok()
+    } else {
+        if value >= u128::MAX as f64 {
+            return None;
+        }
+        T::try_from(value as u128).ok()
+    }
+}
+
+/// Converts an integer to `f64`, rounding only if `round` is set.
+fn int_to_float(value: Result<i128, u128>, round: bool) -> Option<f64> {
+    let (float, exact) = match value {
+        Ok(value) => {
+            let float = value as f64;
+            (float, float < i128::MAX as f64 && float as i128 == value)
+        }
+        Err(value) => {
+            let float = value as f64;
+            (float, float < u128::MAX as f64 && float as u128 == value)
+        }
+    };
+    (exact || round).then_some(float)
+}
+
+macro_rules! impl_try_from_item_for_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl TryFromListItem for $ty {
+                fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError> {
+                    let lenient = mode == Coercion::Lenient;
+                    let value = match (item, item.integer_value()) {
+                        (_, Some(Ok(value))) => <$ty>::try_from(value).ok(),
+                        (_, Some(Err(value))) => <$ty>::try_from(value).ok(),
+                        (ListItem::Float(value), _) => float_to_int(*value, lenient),
+                        (ListItem::Bool(value), _) if lenient => Some(<$ty>::from(*value)),
+                        (ListItem::Str(value), _) if lenient => value.trim().parse().ok(),
+                        _ => None,
+                    };
+                    value.ok_or_else(|| conversion_error::<$ty>(item))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_item_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl TryFromListItem for f64 {
+    fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError> {
+        let lenient = mode == Coercion::Lenient;
+        let value = match (item, item.integer_value()) {
+            (ListItem::Float(value), _) => Some(*value),
+            (_, Some(value)) => int_to_float(value, lenient),
+            (ListItem::Bool(value), _) if lenient => Some(f64::from(u8::from(*value))),
+            (ListItem::Str(value), _) if lenient => value.trim().parse().ok(),
+            _ => None,
+        };
+        value.ok_or_else(|| conversion_error::<f64>(item))
+    }
+}
+
+impl TryFromListItem for bool {
+    fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError> {
+        let lenient = mode == Coercion::Lenient;
+        let value = match (item, item.integer_value()) {
+            (ListItem::Bool(value), _) => Some(*value),
+            (_, Some(Ok(0))) if lenient => Some(false),
+            (_, Some(Ok(1))) if lenient => Some(true),
+            (ListItem::Str(value), _) if lenient => value.trim().parse().ok(),
+            _ => None,
+        };
+        value.ok_or_else(|| conversion_error::<bool>(item))
+    }
+}
+
+impl TryFromListItem for char {
+    fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError> {
+        let value = match item {
+            ListItem::Char(value) => Some(*value),
+            ListItem::Str(value) if mode == Coercion::Lenient => value.parse().ok(),
+            _ => None,
+        };
+        value.ok_or_else(|| conversion_error::<char>(item))
+    }
+}
+
+impl TryFromListItem for String {
+    fn try_from_item(item: &ListItem, mode: Coercion) -> Result<Self, ListError> {
+        match item {
+            ListItem::Str(value) => Ok(value.clone()),
+            ListItem::Char(value) => Ok(value.to_string()),
+            _ if mode == Coercion::Lenient && (item.is_numeric() || item.is_bool()) => {
+                Ok(item.to_string())
+            }
+            _ => Err(conversion_error::<String>(item)),
+        }
+    }
+}
+
+impl List {
+    /// Retrieves the item at the specified index converted to `T` with `Coercion::Strict`.
+    ///
+    /// Unlike `List::get`, this returns an owned value and converts between
+    /// types when no information is lost. Returns
+    /// `ListError::IndexOutOfBounds` if the index is out of bounds and
+    /// `ListError::Conversion` if the item cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let list = list![7, 2.0, 2.5];
+    /// assert_eq!(list.get::<f64>(0), None);
+    /// assert_eq!(list.get_as::<f64>(0), Ok(7.0));
+    /// assert_eq!(list.get_as::<u8>(1), Ok(2));
+    /// assert!(list.get_as::<i32>(2).is_err());
+    /// ```
+    pub fn get_as<T: TryFromListItem>(&self, index: usize) -> Result<T, ListError> {
+        self.get_as_with(index, Coercion::Strict)
+    }
+
+    /// Retrieves the item at the specified index converted to `T` with the given mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, Coercion};
+    ///
+    /// let list = list![2.9, "12", true];
+    /// assert_eq!(list.get_as_with::<i32>(0, Coercion::Lenient), Ok(2));
+    /// assert_eq!(list.get_as_with::<i32>(1, Coercion::Lenient), Ok(12));
+    /// assert_eq!(list.get_as_with::<i32>(2, Coercion::Lenient), Ok(1));
+    /// assert!(list.get_as_with::<i32>(1, Coercion::Strict).is_err());
+    /// ```
+    pub fn get_as_with<T: TryFromListItem>(
+        &self,
+        index: usize,
+        mode: Coercion,
+    ) -> Result<T, ListError> {
+        self.check_index(index)?;
+        T::try_from_item(&self.items[index], mode)
+    }
+}
@@ -0,0 +1,454 @@
+use std::fmt::Write as _;
+use std::io::{BufRead, Write};
+
+use crate::parse::narrowest_integer;
+use crate::{List, ListError, ListItem};
+
+/// How CSV records are split into cells and how cell types are inferred.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{list, CsvOptions, List};
+///
+/// let options = CsvOptions {
+///     delimiter: ';',
+///     null_tokens: vec!["NA".to_string()],
+///     ..CsvOptions::default()
+/// };
+/// let list = List::from_csv_record("1;2,5;NA", &options).unwrap();
+/// assert_eq!(list, list![1, "2,5", ()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// The character separating cells, `,` by default.
+    pub delimiter: char,
+    /// The character quoting cells, `"` by default. Inside a quoted cell the
+    /// delimiter and line breaks are literal, and a doubled quote stands for
+    /// one quote character.
+    pub quote: char,
+    /// Unquoted cells equal to one of these become `Null`. Defaults to the
+    /// empty string, so that empty cells are `Null` while `""` is an empty
+    /// `Str`.
+    pub null_tokens: Vec<String>,
+    /// Restricts type inference to numbers in canonical form.
+    ///
+    /// Quoted cells are always `Str`. By default an unquoted cell becomes a
+    /// number if its text without surrounding whitespace parses as one, so
+    /// ` 42`, `+42` and `042` are all `Int(42)`. With `strict` set, unquoted
+    /// cells are only numbers if they follow the JSON number syntax exactly,
+    /// which keeps values such as zip codes with leading zeros as text.
+    pub strict: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            quote: '"',
+            null_tokens: vec![String::new()],
+            strict: false,
+        }
+    }
+}
+
+impl List {
+    /// Parses one CSV record into a list with one item per cell.
+    ///
+    /// Integer cells become `Int` when they fit in an `i32`, otherwise the
+    /// narrowest of `I64`, `U64`, `I128` and `U128`. Other numeric cells become
+    /// `Float`, unquoted cells matching one of `CsvOptions::null_tokens`
+    /// become `Null`, and everything else, including every quoted cell,
+    /// becomes `Str`. A single trailing line
+    /// break is ignored, and an empty record is an empty list.
+    ///
+    /// Returns `ListError::Parse` with the 1-based line and column of the
+    /// problem if a quoted cell is not closed, is followed by anything but a
+    /// delimiter, or if the record contains a line break outside of quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, CsvOptions, List, ListError};
+    ///
+    /// let options = CsvOptions::default();
+    /// let list = List::from_csv_record("42,3.5,\"a, \"\"b\"\"\",,text\n", &options).unwrap();
+    /// assert_eq!(list, list![42, 3.5, "a, \"b\"", (), "text"]);
+    ///
+    /// assert_eq!(
+    ///     List::from_csv_record("1,\"open", &options),
+    ///     Err(ListError::Parse { line: 1, column: 3 })
+    /// );
+    /// ```
+    pub fn from_csv_record(record: &str, options: &CsvOptions) -> Result<List, ListError> {
+        let mut parser = RecordParser::new(options, 1);
+        parser.feed(strip_line_break(record))?;
+        parser.finish()
+    }
+
+    /// Writes the list as one CSV record, followed by a line break.
+    ///
+    /// Numbers are written in their shortest form, `Float`s always with a
+    /// fraction or exponent, `Null` as the first of `CsvOptions::null_tokens`,
+    /// and `Char`s and `Bool`s as text. Strings are quoted when they contain
+    /// the delimiter, the quote character or a line break, or when they
+    /// would otherwise be read back as a number or `Null`.
+    ///
+    /// Reading the record back with `List::from_csv_record` and the same
+    /// options returns the same list for lists of `Int`, finite `Float`, `Str` and
+    /// `Null` items, as long as there is a null token. Other integer widths
+    /// come back as the narrowest type that fits.
+    ///
+    /// Returns `ListError::Unsupported` if the list contains a nested list, a
+    /// map or a custom value, in which case nothing is written, and
+    /// `ListError::Io` if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, CsvOptions, List};
+    ///
+    /// let options = CsvOptions::default();
+    /// let list = list![1, 2.0, "a,b", "42", "", ()];
+    /// let mut out = Vec::new();
+    /// list.write_csv_record(&mut out, &options).unwrap();
+    /// assert_eq!(out, b"1,2.0,\"a,b\",\"42\",\"\",\n");
+    ///
+    /// let record = String::from_utf8(out).unwrap();
+    /// assert_eq!(List::from_csv_record(&record, &options), Ok(list));
+    /// ```
+    pub fn write_csv_record(
+        &self,
+        writer: &mut impl Write,
+        options: &CsvOptions,
+    ) -> Result<(), ListError> {
+        let mut out = String::new();
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                out.push(options.delimiter);
+            }
+            match item {
+                ListItem::Str(value) => write_text(&mut out, value, options),
+                ListItem::Char(value) => {
+                    write_text(&mut out, value.encode_utf8(&mut [0; 4]), options)
+                }
+                ListItem::Null => {
+                    out.push_str(options.null_tokens.first().map_or("", String::as_str))
+                }
+                ListItem::Float(value) => write!(out, "{value:?}").unwrap(),
+                ListItem::List(_) | ListItem::Map(_) | ListItem::Custom(_) => {
+                    return Err(ListError::Unsupported {
+                        type_name: item.type_name(),
+                        operation: "CSV",
+                    })
+                }
+                // Integers and booleans.
+                _ => write!(out, "{item}").unwrap(),
+            }
+        }
+        out.push('\n');
+        writer.write_all(out.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Reads CSV records from a buffered reader, yielding one `List` per record.
+///
+/// Quoted cells may span several lines. Blank lines yield empty lists. Parse
+/// errors report the line number within the whole input, and reading goes on
+/// with the line after the error. A quoted cell that is never closed takes up
+/// the rest of the input, so the reader yields a single error pointing at its
+/// opening quote and then ends, as it does after an I/O error.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{list, CsvListReader, CsvOptions, List, ListError};
+///
+/// let input = "id,name\n1,\"multi\nline\"\n2,\"bad\"x\n3,ok\n";
+/// let mut reader = CsvListReader::new(input.as_bytes(), CsvOptions::default());
+/// assert_eq!(reader.next(), Some(Ok(list!["id", "name"])));
+/// assert_eq!(reader.next(), Some(Ok(list![1, "multi\nline"])));
+/// assert_eq!(reader.next(), Some(Err(ListError::Parse { line: 4, column: 8 })));
+/// assert_eq!(reader.next(), Some(Ok(list![3, "ok"])));
+/// assert_eq!(reader.next(), None);
+///
+/// let input = "1,\"open\n2,3\n";
+/// let mut reader = CsvListReader::new(input.as_bytes(), CsvOptions::default());
+/// assert_eq!(reader.next(), Some(Err(ListError::Parse { line: 1, column: 3 })));
+/// assert_eq!(reader.next(), None);
+/// ```
+pub struct CsvListReader<R> {
+    reader: R,
+    options: CsvOptions,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> CsvListReader<R> {
+    /// Creates a reader that parses records from `reader` with the given options.
+    pub fn new(reader: R, options: CsvOptions) -> Self {
+        CsvListReader {
+            reader,
+            options,
+            line: 1,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> CsvListReader<R> {
+    /// Reads the next record, or `None` at the end of the input.
+    fn read_record(&mut self) -> Result<Option<List>, ListError> {
+        let mut parser = RecordParser::new(&self.options, self.line);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = self.reader.read_line(&mut line);
+            if matches!(read, Ok(0) | Err(_)) {
+                self.done = true;
+            }
+            if read? == 0 {
+                // At the end of the input, a record still open is an error.
+                return if parser.in_quotes() {
+                    parser.finish().map(Some)
+                } else {
+                    Ok(None)
+                };
+            }
+            self.line += 1;
+            let text = strip_line_break(&line);
+            parser.feed(text)?;
+            if !parser.in_quotes() {
+                return parser.finish().map(Some);
+            }
+            // The line break belongs to the quoted cell.
+            parser.feed(&line[text.len()..])?;
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for CsvListReader<R> {
+    type Item = Result<List, ListError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.read_record().transpose()
+    }
+}
+
+fn strip_line_break(record: &str) -> &str {
+    let record = record.strip_suffix('\n').unwrap_or(record);
+    record.strip_suffix('\r').unwrap_or(record)
+}
+
+#[derive(PartialEq)]
+enum State {
+    FieldStart,
+    Unquoted,
+    Quoted,
+    /// Just after a quote inside a quoted cell, which either closes the cell
+    /// or is the first half of an escaped quote.
+    QuoteInQuoted,
+}
+
+/// Splits a record into cells, fed one piece of text at a time so that a
+/// record spanning several lines is parsed only once.
+struct RecordParser<'a> {
+    options: &'a CsvOptions,
+    list: List,
+    cell: String,
+    state: State,
+    line: usize,
+    column: usize,
+    opening_quote: (usize, usize),
+}
+
+impl<'a> RecordParser<'a> {
+    fn new(options: &'a CsvOptions, first_line: usize) -> Self {
+        RecordParser {
+            options,
+            list: List::new(),
+            cell: String::new(),
+            state: State::FieldStart,
+            line: first_line,
+            column: 1,
+            opening_quote: (first_line, 1),
+        }
+    }
+
+    fn feed(&mut self, text: &str) -> Result<(), ListError> {
+        let options = self.options;
+        for c in text.chars() {
+            match self.state {
+                State::FieldStart | State::Unquoted if c == options.delimiter => {
+                    self.end_cell(false);
+                }
+                State::FieldStart | State::Unquoted if c == '\r' || c == '\n' => {
+                    return Err(self.error());
+                }
+                State::FieldStart if c == options.quote => {
+                    self.opening_quote = (self.line, self.column);
+                    self.state = State::Quoted;
+                }
+                State::FieldStart | State::Unquoted => {
+                    self.cell.push(c);
+                    self.state = State::Unquoted;
+                }
+                State::Quoted if c == options.quote => self.state = State::QuoteInQuoted,
+                State::Quoted => self.cell.push(c),
+                State::QuoteInQuoted if c == options.quote => {
+                    self.cell.push(c);
+                    self.state = State::Quoted;
+                }
+                State::QuoteInQuoted if c == options.delimiter => self.end_cell(true),
+                State::QuoteInQuoted => return Err(self.error()),
+            }
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the text so far ends inside a quoted cell, which more lines
+    /// may close.
+    fn in_quotes(&self) -> bool {
+        self.state == State::Quoted
+    }
+
+    fn finish(mut self) -> Result<List, ListError> {
+        if self.in_quotes() {
+            let (line, column) = self.opening_quote;
+            return Err(ListError::Parse { line, column });
+        }
+        // Every character but a quote moves past the field start or ends a
+        // cell, so this only holds for an empty record.
+        if self.state == State::FieldStart && self.list.is_empty() {
+            return Ok(self.list);
+        }
+        self.end_cell(self.state == State::QuoteInQuoted);
+        Ok(self.list)
+    }
+
+    fn end_cell(&mut self, quoted: bool) {
+        let cell = std::mem::take(&mut self.cell);
+        self.list.insert(infer(cell, quoted, self.options));
+        self.state = State::FieldStart;
+    }
+
+    /// Returns a parse error pointing at the current position.
+    fn error(&self) -> ListError {
+        ListError::Parse {
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+fn infer(text: String, quoted: bool, options: &CsvOptions) -> ListItem {
+    if quoted {
+        return ListItem::Str(text);
+    }
+    if options.null_tokens.contains(&text) {
+        return ListItem::Null;
+    }
+    parse_number(&text, options.strict).unwrap_or(ListItem::Str(text))
+}
+
+fn parse_number(text: &str, strict: bool) -> Option<ListItem> {
+    let is_float = if strict {
+        json_number(text)?
+    } else {
+        lenient_number(text.trim())?
+    };
+    let text = if strict { text } else { text.trim() };
+    if !is_float {
+        if let Ok(value) = text.parse::<i128>() {
+            return Some(narrowest_integer(value));
+        }
+        if let Ok(value) = text.parse::<u128>() {
+            return Some(ListItem::U128(value));
+        }
+    }
+    let value: f64 = text.parse().ok()?;
+    value.is_finite().then_some(ListItem::Float(value))
+}
+
+/// Returns whether `text` is a float, if it follows the JSON number syntax
+/// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`.
+fn json_number(text: &str) -> Option<bool> {
+    let bytes = text.as_bytes();
+    let digits = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+    let mut pos = usize::from(bytes.first() == Some(&b'-'));
+    match digits(pos) {
+        0 => return None,
+        n if n > 1 && bytes[pos] == b'0' => return None,
+        n => pos += n,
+    }
+    let mut is_float = false;
+    if bytes.get(pos) == Some(&b'.') {
+        is_float = true;
+        match digits(pos + 1) {
+            0 => return None,
+            n => pos += 1 + n,
+        }
+    }
+    if let Some(b'e' | b'E') = bytes.get(pos) {
+        is_float = true;
+        pos += 1;
+        if let Some(b'+' | b'-') = bytes.get(pos) {
+            pos += 1;
+        }
+        match digits(pos) {
+            0 => return None,
+            n => pos += n,
+        }
+    }
+    (pos == bytes.len()).then_some(is_float)
+}
+
+/// Returns whether `text` is a float, if it is an integer with an optional
+/// sign or looks like a decimal float. Spelled-out values such as `inf` and
+/// `NaN` are not numbers.
+fn lenient_number(text: &str) -> Option<bool> {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    if !unsigned.is_empty() && unsigned.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Some(false);
+    }
+    let numeric = text.bytes().any(|byte| byte.is_ascii_digit())
+        && text
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || b"+-.eE".contains(&byte));
+    numeric.then_some(true)
+}
+
+/// Writes text, quoted if reading it back would split it or infer another type.
+fn write_text(out: &mut String, text: &str, options: &CsvOptions) {
+    let needs_quotes = text.is_empty()
+        || text.contains([options.delimiter, options.quote, '\r', '\n'])
+        || options.null_tokens.iter().any(|token| token == text)
+        || parse_number(text, false).is_some();
+    if !needs_quotes {
+        out.push_str(text);
+        return;
+    }
+    out.push(options.quote);
+    for c in text.chars() {
+        if c == options.quote {
+            out.push(c);
+        }
+        out.push(c);
+    }
+    out.push(options.quote);
+}
@@ -0,0 +1,91 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The error type returned by fallible `List` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ListError {
+    /// The index is not smaller than the length of the list.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The range is reversed or extends past the end of the list.
+    InvalidRange {
+        start: usize,
+        end: usize,
+        len: usize,
+    },
+    /// The item at the index holds a different type than the one requested.
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The item cannot be converted to the requested type.
+    Conversion {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// An integer aggregation does not fit in an `i128`.
+    Overflow { operation: &'static str },
+    /// The input text is malformed at the given 1-based line and column.
+    Parse { line: usize, column: usize },
+    /// The binary input is malformed at the given byte offset.
+    Decode { offset: usize, reason: &'static str },
+    /// The item type cannot be represented by the operation, such as a custom value in the binary format.
+    Unsupported {
+        type_name: &'static str,
+        operation: &'static str,
+    },
+    /// Lists and maps nest deeper than the format allows.
+    TooDeep { limit: usize },
+    /// Reading or writing failed with an I/O error of the given kind.
+    Io { kind: io::ErrorKind },
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for list of length {len}")
+            }
+            ListError::InvalidRange { start, end, len } => {
+                write!(
+                    f,
+                    "range {start}..{end} is invalid for list of length {len}"
+                )
+            }
+            ListError::TypeMismatch { expected, found } => {
+                write!(f, "type mismatch: expected `{expected}`, found `{found}`")
+            }
+            ListError::Conversion { from, to } => {
+                write!(f, "cannot convert `{from}` to `{to}`")
+            }
+            ListError::Overflow { operation } => {
+                write!(f, "integer overflow while computing the {operation}")
+            }
+            ListError::Parse { line, column } => {
+                write!(f, "parse error at line {line}, column {column}")
+            }
+            ListError::Decode { offset, reason } => {
+                write!(f, "decode error at byte {offset}: {reason}")
+            }
+            ListError::Unsupported {
+                type_name,
+                operation,
+            } => {
+                write!(f, "`{type_name}` is not supported by {operation}")
+            }
+            ListError::TooDeep { limit } => {
+                write!(f, "lists and maps nest more than {limit} levels deep")
+            }
+            ListError::Io { kind } => write!(f, "I/O error: {kind}"),
+        }
+    }
+}
+
+impl Error for ListError {}
+
+impl From<io::Error> for ListError {
+    fn from(error: io::Error) -> Self {
+        ListError::Io { kind: error.kind() }
+    }
+}
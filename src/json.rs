@@ -0,0 +1,391 @@
+use std::fmt::Write;
+
+use crate::parse::{check_depth, narrowest_integer, parse_error, MAX_DEPTH};
+use crate::{List, ListError, ListItem, ListMap};
+
+impl List {
+    /// Serializes the list as a compact JSON array.
+    ///
+    /// Integers of every width are written as JSON integers and `Float`s
+    /// always carry a fraction or exponent (`1.0`, `1e300`), so
+    /// `List::from_json` reads them back as `Float`. `Char`s are written as
+    /// one-character strings, `Null` as `null`, nested lists as arrays and
+    /// maps as objects. JSON has no representation for `NaN` and infinities,
+    /// which are written as `null`.
+    ///
+    /// Returns `ListError::Unsupported` if the list contains a custom value,
+    /// and `ListError::TooDeep` if lists and maps nest more than 128 levels
+    /// deep, which `List::from_json` would reject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListError};
+    ///
+    /// let list = list![1, 2.0, "say \"hi\"", true, (), list!['x']];
+    /// assert_eq!(list.to_json().unwrap(), r#"[1,2.0,"say \"hi\"",true,null,["x"]]"#);
+    ///
+    /// let mut deep = list![];
+    /// for _ in 0..128 {
+    ///     deep = list![deep];
+    /// }
+    /// assert_eq!(deep.to_json(), Err(ListError::TooDeep { limit: 128 }));
+    /// ```
+    pub fn to_json(&self) -> Result<String, ListError> {
+        let mut out = String::new();
+        write_list(&mut out, self, 0)?;
+        Ok(out)
+    }
+
+    /// Parses a JSON array into a list.
+    ///
+    /// Integers become `Int` when they fit in an `i32`, otherwise the
+    /// narrowest of `I64`, `U64`, `I128` and `U128`; numbers with a fraction or
+    /// exponent, and integers too large for any of those, become `Float`.
+    /// Strings become `Str`, `true` and `false` become `Bool`, `null` becomes
+    /// `Null`, arrays become nested `List`s and objects become `ListMap`s.
+    ///
+    /// Returns `ListError::Parse` with the 1-based line and column of the
+    /// first offending character if the input is not a single JSON array, or
+    /// if it nests more than 128 levels deep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, List, ListError, ListMap};
+    ///
+    /// let list = List::from_json(r#"[1, 2.5, "a\nb", [null], {"k": false}]"#).unwrap();
+    /// let mut map = ListMap::new();
+    /// map.insert("k", false);
+    /// assert_eq!(list, list![1, 2.5, "a\nb", list![()], map]);
+    ///
+    /// assert_eq!(
+    ///     List::from_json("[1,\n 2,,]"),
+    ///     Err(ListError::Parse { line: 2, column: 4 })
+    /// );
+    /// ```
+    pub fn from_json(input: &str) -> Result<List, ListError> {
+        let mut parser = Parser { input, pos: 0 };
+        parser.skip_whitespace();
+        if parser.peek() != Some(b'[') {
+            return Err(parser.error());
+        }
+        let list = parser.parse_list(0)?;
+        parser.skip_whitespace();
+        if parser.pos < input.len() {
+            return Err(parser.error());
+        }
+        Ok(list)
+    }
+}
+
+fn write_list(out: &mut String, list: &List, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    out.push('[');
+    for (i, item) in list.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_item(out, item, depth + 1)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+fn write_map(out: &mut String, map: &ListMap, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    out.push('{');
+    for (i, (key, value)) in map.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_string(out, key);
+        out.push(':');
+        write_item(out, value, depth + 1)?;
+    }
+    out.push('}');
+    Ok(())
+}
+
+fn write_item(out: &mut String, item: &ListItem, depth: usize) -> Result<(), ListError> {
+    match item {
+        ListItem::Str(value) => write_string(out, value),
+        ListItem::Char(value) => write_string(out, value.encode_utf8(&mut [0; 4])),
+        // `Debug` keeps the fraction of integral floats (`1.0`) and switches
+        // to exponent notation for very large and small magnitudes, both of
+        // which are valid JSON numbers.
+        ListItem::Float(value) if value.is_finite() => write!(out, "{value:?}").unwrap(),
+        ListItem::Float(_) | ListItem::Null => out.push_str("null"),
+        ListItem::List(value) => return write_list(out, value, depth),
+        ListItem::Map(value) => return write_map(out, value, depth),
+        ListItem::Custom(_) => {
+            return Err(ListError::Unsupported {
+                type_name: item.type_name(),
+                operation: "JSON",
+            })
+        }
+        // Integers and booleans.
+        _ => write!(out, "{item}").unwrap(),
+    }
+    Ok(())
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A recursive descent JSON parser over the bytes of the input.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    /// Returns a parse error pointing at the current position.
+    fn error(&self) -> ListError {
+        self.error_at(self.pos)
+    }
+
+    fn error_at(&self, pos: usize) -> ListError {
+        parse_error(self.input, pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ListError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<ListItem, ListError> {
+        match self.peek() {
+            Some(b'[') => self.parse_list(depth).map(ListItem::List),
+            Some(b'{') => self.parse_map(depth).map(ListItem::Map),
+            Some(b'"') => self.parse_string().map(ListItem::Str),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b't') => self.parse_keyword("true", ListItem::Bool(true)),
+            Some(b'f') => self.parse_keyword("false", ListItem::Bool(false)),
+            Some(b'n') => self.parse_keyword("null", ListItem::Null),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, item: ListItem) -> Result<ListItem, ListError> {
+        for &byte in keyword.as_bytes() {
+            self.expect(byte)?;
+        }
+        Ok(item)
+    }
+
+    fn parse_list(&mut self, depth: usize) -> Result<List, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.expect(b'[')?;
+        let mut list = List::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(list);
+        }
+        loop {
+            self.skip_whitespace();
+            list.insert(self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(list);
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn parse_map(&mut self, depth: usize) -> Result<ListMap, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.expect(b'{')?;
+        let mut map = ListMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(map);
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error());
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.skip_whitespace();
+            map.insert(key, self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(map);
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ListError> {
+        self.expect(b'"')?;
+        let mut value = String::new();
+        loop {
+            let rest = &self.input[self.pos..];
+            let Some(c) = rest.chars().next() else {
+                return Err(self.error());
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(value);
+                }
+                '\\' => {
+                    self.pos += 1;
+                    value.push(self.parse_escape()?);
+                }
+                c if c < ' ' => return Err(self.error()),
+                c => {
+                    value.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+    }
+
+    /// Parses the escape sequence following a backslash.
+    fn parse_escape(&mut self) -> Result<char, ListError> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let start = self.pos - 1;
+                self.pos += 1;
+                let high = self.parse_hex()?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    // A high surrogate must be followed by an escaped low surrogate.
+                    if !self.input[self.pos..].starts_with("\\u") {
+                        return Err(self.error_at(start));
+                    }
+                    self.pos += 2;
+                    let low = self.parse_hex()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error_at(start));
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                return char::from_u32(code).ok_or_else(|| self.error_at(start));
+            }
+            _ => return Err(self.error()),
+        };
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, ListError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error())?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn parse_number(&mut self) -> Result<ListItem, ListError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.error()),
+        }
+        let mut is_float = false;
+        if self.peek() == Some(b'.') {
+            is_float = true;
+            self.pos += 1;
+            self.expect_digits()?;
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            is_float = true;
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            self.expect_digits()?;
+        }
+
+        let text = &self.input[start..self.pos];
+        if !is_float {
+            if let Ok(value) = text.parse::<i128>() {
+                return Ok(narrowest_integer(value));
+            }
+            if let Ok(value) = text.parse::<u128>() {
+                return Ok(ListItem::U128(value));
+            }
+        }
+        match text.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(ListItem::Float(value)),
+            _ => Err(self.error_at(start)),
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect_digits(&mut self) -> Result<(), ListError> {
+        if !matches!(self.peek(), Some(b'0'..=b'9')) {
+            return Err(self.error());
+        }
+        self.skip_digits();
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::{List, ListItem, ListMap, ListValue};
+
+/// The kind of value held by a `ListItem`, one per variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ListItemKind {
+    Int,
+    I8,
+    I16,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    Str,
+    Float,
+    Bool,
+    Char,
+    Null,
+    List,
+    Map,
+    Custom,
+}
+
+impl ListItemKind {
+    /// Every kind, in declaration order.
+    pub const ALL: [ListItemKind; 20] = [
+        ListItemKind::Int,
+        ListItemKind::I8,
+        ListItemKind::I16,
+        ListItemKind::I64,
+        ListItemKind::I128,
+        ListItemKind::Isize,
+        ListItemKind::U8,
+        ListItemKind::U16,
+        ListItemKind::U32,
+        ListItemKind::U64,
+        ListItemKind::U128,
+        ListItemKind::Usize,
+        ListItemKind::Str,
+        ListItemKind::Float,
+        ListItemKind::Bool,
+        ListItemKind::Char,
+        ListItemKind::Null,
+        ListItemKind::List,
+        ListItemKind::Map,
+        ListItemKind::Custom,
+    ];
+
+    /// Returns the name of the `ListItem` variant of this kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(rusty_list::ListItemKind::Float.name(), "Float");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            ListItemKind::Int => "Int",
+            ListItemKind::I8 => "I8",
+            ListItemKind::I16 => "I16",
+            ListItemKind::I64 => "I64",
+            ListItemKind::I128 => "I128",
+            ListItemKind::Isize => "Isize",
+            ListItemKind::U8 => "U8",
+            ListItemKind::U16 => "U16",
+            ListItemKind::U32 => "U32",
+            ListItemKind::U64 => "U64",
+            ListItemKind::U128 => "U128",
+            ListItemKind::Usize => "Usize",
+            ListItemKind::Str => "Str",
+            ListItemKind::Float => "Float",
+            ListItemKind::Bool => "Bool",
+            ListItemKind::Char => "Char",
+            ListItemKind::Null => "Null",
+            ListItemKind::List => "List",
+            ListItemKind::Map => "Map",
+            ListItemKind::Custom => "Custom",
+        }
+    }
+
+    /// Returns `true` for the integer kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::ListItemKind;
+    ///
+    /// assert!(ListItemKind::U64.is_integer());
+    /// assert!(!ListItemKind::Float.is_integer());
+    /// ```
+    pub fn is_integer(self) -> bool {
+        matches!(
+            self,
+            ListItemKind::Int
+                | ListItemKind::I8
+                | ListItemKind::I16
+                | ListItemKind::I64
+                | ListItemKind::I128
+                | ListItemKind::Isize
+                | ListItemKind::U8
+                | ListItemKind::U16
+                | ListItemKind::U32
+                | ListItemKind::U64
+                | ListItemKind::U128
+                | ListItemKind::Usize
+        )
+    }
+
+    /// Returns `true` for the integer kinds and `Float`.
+    pub fn is_numeric(self) -> bool {
+        self.is_integer() || self == ListItemKind::Float
+    }
+}
+
+// Implementation of Display for ListItemKind
+impl fmt::Display for ListItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl ListItem {
+    /// Returns the kind of the item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{ListItem, ListItemKind};
+    ///
+    /// assert_eq!(ListItem::from(42).kind(), ListItemKind::Int);
+    /// assert_eq!(ListItem::from("a").kind(), ListItemKind::Str);
+    /// ```
+    pub fn kind(&self) -> ListItemKind {
+        match self {
+            ListItem::Int(_) => ListItemKind::Int,
+            ListItem::I8(_) => ListItemKind::I8,
+            ListItem::I16(_) => ListItemKind::I16,
+            ListItem::I64(_) => ListItemKind::I64,
+            ListItem::I128(_) => ListItemKind::I128,
+            ListItem::Isize(_) => ListItemKind::Isize,
+            ListItem::U8(_) => ListItemKind::U8,
+            ListItem::U16(_) => ListItemKind::U16,
+            ListItem::U32(_) => ListItemKind::U32,
+            ListItem::U64(_) => ListItemKind::U64,
+            ListItem::U128(_) => ListItemKind::U128,
+            ListItem::Usize(_) => ListItemKind::Usize,
+            ListItem::Str(_) => ListItemKind::Str,
+            ListItem::Float(_) => ListItemKind::Float,
+            ListItem::Bool(_) => ListItemKind::Bool,
+            ListItem::Char(_) => ListItemKind::Char,
+            ListItem::Null => ListItemKind::Null,
+            ListItem::List(_) => ListItemKind::List,
+            ListItem::Map(_) => ListItemKind::Map,
+            ListItem::Custom(_) => ListItemKind::Custom,
+        }
+    }
+
+    /// Returns the name of the stored Rust type, as reported by `std::any::type_name`.
+    ///
+    /// This is the type to request from `List::get`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::ListItem;
+    ///
+    /// assert_eq!(ListItem::from(1u8).type_name(), "u8");
+    /// assert_eq!(ListItem::Null.type_name(), "()");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ListItem::Int(_) => std::any::type_name::<i32>(),
+            ListItem::I8(_) => std::any::type_name::<i8>(),
+            ListItem::I16(_) => std::any::type_name::<i16>(),
+            ListItem::I64(_) => std::any::type_name::<i64>(),
+            ListItem::I128(_) => std::any::type_name::<i128>(),
+            ListItem::Isize(_) => std::any::type_name::<isize>(),
+            ListItem::U8(_) => std::any::type_name::<u8>(),
+            ListItem::U16(_) => std::any::type_name::<u16>(),
+            ListItem::U32(_) => std::any::type_name::<u32>(),
+            ListItem::U64(_) => std::any::type_name::<u64>(),
+            ListItem::U128(_) => std::any::type_name::<u128>(),
+            ListItem::Usize(_) => std::any::type_name::<usize>(),
+            ListItem::Str(_) => std::any::type_name::<String>(),
+            ListItem::Float(_) => std::any::type_name::<f64>(),
+            ListItem::Bool(_) => std::any::type_name::<bool>(),
+            ListItem::Char(_) => std::any::type_name::<char>(),
+            ListItem::Null => std::any::type_name::<()>(),
+            ListItem::List(_) => std::any::type_name::<List>(),
+            ListItem::Map(_) => std::any::type_name::<ListMap>(),
+            ListItem::Custom(value) => value.type_name(),
+        }
+    }
+
+    /// Returns `true` if the item is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, ListItem::Null)
+    }
+
+    /// Returns `true` if the item holds an integer of any width.
+    pub fn is_integer(&self) -> bool {
+        self.kind().is_integer()
+    }
+
+    /// Returns `true` if the item holds an integer of any width or a `Float`.
+    pub fn is_numeric(&self) -> bool {
+        self.kind().is_numeric()
+    }
+}
+
+macro_rules! impl_copy_accessors {
+    ($($variant:ident($ty:ty) => $is:ident, $as:ident;)*) => {
+        impl ListItem {
+            $(
+                #[doc = concat!("Returns `true` if the item is a `", stringify!($variant), "`.")]
+                pub fn $is(&self) -> bool {
+                    matches!(self, ListItem::$variant(_))
+                }
+
+                #[doc = concat!("Returns the `", stringify!($ty), "` value if the item is a `", stringify!($variant), "`.")]
+                pub fn $as(&self) -> Option<$ty> {
+                    match self {
+                        ListItem::$variant(value) => Some(*value),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_copy_accessors! {
+    Int(i32) => is_int, as_int;
+    I8(i8) => is_i8, as_i8;
+    I16(i16) => is_i16, as_i16;
+    I64(i64) => is_i64, as_i64;
+    I128(i128) => is_i128, as_i128;
+    Isize(isize) => is_isize, as_isize;
+    U8(u8) => is_u8, as_u8;
+    U16(u16) => is_u16, as_u16;
+    U32(u32) => is_u32, as_u32;
+    U64(u64) => is_u64, as_u64;
+    U128(u128) => is_u128, as_u128;
+    Usize(usize) => is_usize, as_usize;
+    Float(f64) => is_float, as_float;
+    Bool(bool) => is_bool, as_bool;
+    Char(char) => is_char, as_char;
+}
+
+macro_rules! impl_ref_accessors {
+    ($($variant:ident($ty:ty) => $is:ident, $as:ident, $as_mut:ident, $into:ident;)*) => {
+        impl ListItem {
+            $(
+                #[doc = concat!("Returns `true` if the item is a `", stringify!($variant), "`.")]
+                pub fn $is(&self) -> bool {
+                    matches!(self, ListItem::$variant(_))
+                }
+
+                #[doc = concat!("Returns a reference to the `", stringify!($ty), "` if the item is a `", stringify!($variant), "`.")]
+                pub fn $as(&self) -> Option<&$ty> {
+                    match self {
+                        ListItem::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Returns a mutable reference to the `", stringify!($ty), "` if the item is a `", stringify!($variant), "`.")]
+                pub fn $as_mut(&mut self) -> Option<&mut $ty> {
+                    match self {
+                        ListItem::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Returns the `", stringify!($ty), "` if the item is a `", stringify!($variant), "`, or the item itself otherwise.")]
+                pub fn $into(self) -> Result<$ty, ListItem> {
+                    match self {
+                        ListItem::$variant(value) => Ok(value),
+                        item => Err(item),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_ref_accessors! {
+    Str(String) => is_str, as_string, as_string_mut, into_string;
+    List(List) => is_list, as_list, as_list_mut, into_list;
+    Map(ListMap) => is_map, as_map, as_map_mut, into_map;
+    Custom(Box<dyn ListValue>) => is_custom, as_custom, as_custom_mut, into_custom;
+}
+
+impl ListItem {
+    /// Returns the string slice if the item is a `Str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::ListItem;
+    ///
+    /// let item = ListItem::from("hello");
+    /// assert!(item.is_str());
+    /// assert_eq!(item.as_str(), Some("hello"));
+    /// assert_eq!(item.as_int(), None);
+    /// assert_eq!(item.into_string(), Ok("hello".to_string()));
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_string().map(String::as_str)
+    }
+}
+
+impl List {
+    /// Returns the distinct kinds of the items in the list, in `ListItemKind` order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItemKind};
+    ///
+    /// let list = list![1, "a", 2, 0.5];
+    /// let kinds: Vec<_> = list.kinds().into_iter().collect();
+    /// assert_eq!(kinds, [ListItemKind::Int, ListItemKind::Str, ListItemKind::Float]);
+    /// ```
+    pub fn kinds(&self) -> BTreeSet<ListItemKind> {
+        self.items.iter().map(ListItem::kind).collect()
+    }
+
+    /// Counts the items of each kind in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItemKind};
+    ///
+    /// let counts = list![1, "a", 2, ()].count_by_kind();
+    /// assert_eq!(counts[&ListItemKind::Int], 2);
+    /// assert_eq!(counts[&ListItemKind::Str], 1);
+    /// assert_eq!(counts.get(&ListItemKind::Float), None);
+    /// ```
+    pub fn count_by_kind(&self) -> BTreeMap<ListItemKind, usize> {
+        let mut counts = BTreeMap::new();
+        for item in &self.items {
+            *counts.entry(item.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
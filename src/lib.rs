@@ -1,15 +1,183 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+mod binary;
+mod columnar;
+mod convert;
+mod csv;
+mod error;
+mod json;
+mod kind;
+mod literal;
+mod map;
+mod ord;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod slice;
+mod stats;
+
+pub use columnar::{ColumnarIter, ColumnarList, ListItemRef};
+pub use convert::{Coercion, TryFromListItem};
+pub use csv::{CsvListReader, CsvOptions};
+pub use error::ListError;
+pub use kind::ListItemKind;
+pub use map::ListMap;
+#[cfg(feature = "serde")]
+pub use serde_impl::Tagged;
+pub use slice::ListSlice;
+pub use stats::Number;
 
 /// A custom list that can store values of different types.
+///
+/// Every primitive integer width has its own variant, and `List::get` only
+/// returns the exact stored type: `get::<i64>` on an `Int(i32)` slot is `None`,
+/// it never widens.
+///
+/// Equality and hashing are structural: items of different variants are never
+/// equal, so `Int(1) != I64(1)`. `Float` values compare by their bit pattern,
+/// which makes `NaN` equal to itself and `0.0` different from `-0.0`, so that
+/// `ListItem` and `List` can implement `Eq` and `Hash`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use rusty_list::{list, ListItem};
+///
+/// assert_eq!(ListItem::Float(f64::NAN), ListItem::Float(f64::NAN));
+/// assert_ne!(ListItem::Float(0.0), ListItem::Float(-0.0));
+/// assert_ne!(ListItem::Int(1), ListItem::I64(1));
+///
+/// let unique: HashSet<_> = [list![1, "a"], list![1, "a"], list![2.5]].into_iter().collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
 pub enum ListItem {
     Int(i32),
+    I8(i8),
+    I16(i16),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
     Str(String),
     Float(f64),
-    // Add other types as needed
+    Bool(bool),
+    Char(char),
+    /// A missing value. Retrieved as `()` through `List::get`.
+    Null,
+    /// A nested list, for tree-shaped data.
+    List(List),
+    /// A string-keyed map that preserves insertion order.
+    Map(ListMap),
+    /// A value of a user-defined type, see `ListValue`.
+    Custom(Box<dyn ListValue>),
+}
+
+/// A user-defined type that can be stored in a `List`.
+///
+/// Implementing this trait lets `List::insert` accept the type directly, and
+/// `List::get` downcasts back to it like any built-in type. The type must be
+/// `Clone` and `Ord` so that lists holding it can be cloned, compared, sorted
+/// and hashed: every value has to equal its own clone, which rules out
+/// wrapping a bare `f64` that may be `NaN`, and values that are not equal must
+/// not compare as `Equal`.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+/// use rusty_list::{List, ListValue};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Point(i32, i32);
+///
+/// impl fmt::Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "({}, {})", self.0, self.1)
+///     }
+/// }
+///
+/// impl ListValue for Point {}
+///
+/// let mut list = List::new();
+/// list.insert(Point(1, 2));
+/// assert_eq!(list.get::<Point>(0), Some(&Point(1, 2)));
+/// assert_eq!(list.to_string(), "[(1, 2)]");
+/// assert_eq!(list.clone(), list);
+///
+/// list.insert(Point(0, 5));
+/// list.sort();
+/// assert_eq!(list.get::<Point>(0), Some(&Point(0, 5)));
+/// ```
+pub trait ListValue: Any + fmt::Debug + fmt::Display + Send + Sync + DynListValue {
+    /// Returns the name of the implementing type.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Feeds the value into the hasher.
+    ///
+    /// `ListItem` already hashes the type of a custom value, so the default
+    /// does nothing. Override it to spread equal-typed values across buckets.
+    fn hash_value(&self, state: &mut dyn Hasher) {
+        let _ = state;
+    }
 }
 
+/// Object-safe operations on a `ListValue`, implemented for every `ListValue`
+/// that is also `Clone` and `Ord`.
+pub trait DynListValue {
+    /// Returns a boxed clone of the value.
+    fn clone_value(&self) -> Box<dyn ListValue>;
+
+    /// Returns `true` if `other` is of the same type and equal to the value.
+    fn eq_value(&self, other: &dyn Any) -> bool;
+
+    /// Compares the value with `other`, or returns `None` if `other` is of
+    /// another type.
+    fn cmp_value(&self, other: &dyn Any) -> Option<Ordering>;
+}
+
+impl<T: ListValue + Clone + Ord> DynListValue for T {
+    fn clone_value(&self) -> Box<dyn ListValue> {
+        Box::new(self.clone())
+    }
+
+    fn eq_value(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>() == Some(self)
+    }
+
+    fn cmp_value(&self, other: &dyn Any) -> Option<Ordering> {
+        other.downcast_ref::<T>().map(|other| self.cmp(other))
+    }
+}
+
+impl Clone for Box<dyn ListValue> {
+    fn clone(&self) -> Self {
+        (**self).clone_value()
+    }
+}
+
+/// A list of `ListItem`s of any type.
+///
+/// Items are stored in a ring buffer, so adding or removing items at either
+/// end is amortized O(1) while indexing stays O(1). Lists are ordered
+/// lexicographically using the total order of `ListItem`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct List {
-    items: Vec<ListItem>,
+    items: VecDeque<ListItem>,
 }
 
 impl List {
@@ -21,7 +189,9 @@ impl List {
     /// let list = rusty_list::List::new();
     /// ```
     pub fn new() -> Self {
-        List { items: Vec::new() }
+        List {
+            items: VecDeque::new(),
+        }
     }
 
     /// Inserts a value at the end of the list.
@@ -34,11 +204,13 @@ impl List {
     /// assert_eq!(list.get::<i32>(0), Some(&42));
     /// ```
     pub fn insert<T: Into<ListItem>>(&mut self, value: T) {
-        self.items.push(value.into());
+        self.items.push_back(value.into());
     }
 
     /// Inserts a value at the beginning of the list.
     ///
+    /// This is amortized O(1), like inserting at the end.
+    ///
     /// # Examples
     ///
     /// ```
@@ -46,52 +218,239 @@ impl List {
     /// list.insert_at_beginning(42);
     /// ```
     pub fn insert_at_beginning<T: Into<ListItem>>(&mut self, value: T) {
-        self.items.insert(0, value.into());
+        self.items.push_front(value.into());
     }
 
-    /// Replaces the item at the specified index with a new value.
+    /// Appends a value to the back of the list, the same as `List::insert`.
+    ///
+    /// # Examples
     ///
-    /// Returns `Err` if the index is out of bounds.
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.push_back(1);
+    /// list.push_front(0);
+    /// assert_eq!(list.to_string(), "[0, 1]");
+    /// ```
+    pub fn push_back<T: Into<ListItem>>(&mut self, value: T) {
+        self.insert(value);
+    }
+
+    /// Prepends a value to the front of the list, the same as `List::insert_at_beginning`.
+    pub fn push_front<T: Into<ListItem>>(&mut self, value: T) {
+        self.insert_at_beginning(value);
+    }
+
+    /// Inserts a value at the specified index, shifting all items after it to the right.
+    ///
+    /// An index equal to the length appends the value. Returns
+    /// `ListError::IndexOutOfBounds` if the index is greater than the length.
     ///
     /// # Examples
     ///
     /// ```
     /// let mut list = rusty_list::List::new();
-    /// list.insert(42);
-    /// list.replace(0, 43).unwrap();
-    /// assert_eq!(list.get::<i32>(0), Some(&43));
+    /// list.insert(1);
+    /// list.insert(3);
+    /// list.insert_at(1, 2).unwrap();
+    /// assert_eq!(list.to_string(), "[1, 2, 3]");
+    /// assert!(list.insert_at(5, 4).is_err());
     /// ```
-    pub fn replace<T: Into<ListItem>>(
+    pub fn insert_at<T: Into<ListItem>>(
         &mut self,
         index: usize,
         value: T,
-    ) -> Result<(), &'static str> {
-        if index < self.items.len() {
-            self.items[index] = value.into();
-            Ok(())
-        } else {
-            Err("Index out of range")
+    ) -> Result<(), ListError> {
+        if index > self.items.len() {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            });
+        }
+        self.items.insert(index, value.into());
+        Ok(())
+    }
+
+    /// Replaces the items in the range with the given values and returns the removed items.
+    ///
+    /// The replacement does not need to have the same length as the range.
+    /// Returns `ListError::InvalidRange` if the range is reversed or extends
+    /// past the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// list.insert(3);
+    /// let removed = list.splice(1..2, [20, 21, 22]).unwrap();
+    /// assert_eq!(removed.to_string(), "[2]");
+    /// assert_eq!(list.to_string(), "[1, 20, 21, 22, 3]");
+    /// assert!(list.splice(4..9, [0]).is_err());
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<List, ListError>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator,
+        I::Item: Into<ListItem>,
+    {
+        let range = self.check_range(range)?;
+        let mut tail = self.items.split_off(range.end);
+        let removed = self.items.drain(range.start..).collect();
+        self.items.extend(replace_with.into_iter().map(Into::into));
+        self.items.append(&mut tail);
+        Ok(List { items: removed })
+    }
+
+    /// Splits the list in two at the specified index.
+    ///
+    /// Returns a new list with the items from `index` onwards, leaving the
+    /// items before it in `self`. Returns `ListError::IndexOutOfBounds` if the
+    /// index is greater than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert("two".to_string());
+    /// list.insert(3.0);
+    /// let tail = list.split_off(1).unwrap();
+    /// assert_eq!(list.to_string(), "[1]");
+    /// assert_eq!(tail.to_string(), "[two, 3]");
+    /// ```
+    pub fn split_off(&mut self, index: usize) -> Result<List, ListError> {
+        if index > self.items.len() {
+            return Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            });
         }
+        Ok(List {
+            items: self.items.split_off(index),
+        })
     }
 
+    /// Replaces the item at the specified index with a new value.
+    ///
+    /// Returns `ListError::IndexOutOfBounds` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(42);
+    /// list.replace(0, 43).unwrap();
+    /// assert_eq!(list.get::<i32>(0), Some(&43));
+    /// ```
+    pub fn replace<T: Into<ListItem>>(&mut self, index: usize, value: T) -> Result<(), ListError> {
+        self.check_index(index)?;
+        self.items[index] = value.into();
+        Ok(())
+    }
 
-    /// Removes the item at the specified index
-    /// 
+    /// Removes the item at the specified index and returns it.
+    ///
+    /// Returns `ListError::IndexOutOfBounds` if the index is out of bounds.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// let mut list = rusty_list::List::new();
     /// list.insert(42);
     /// list.insert("leet".to_string());
-    /// list.remove(0);
+    /// assert_eq!(list.remove(0).unwrap().to_string(), "42");
     /// assert_eq!(list.get::<String>(0), Some(&String::from("leet")));
+    /// assert!(list.remove(1).is_err());
     /// ```
-    pub fn remove(&mut self, index: usize) {
-        if index < self.items.len() {
-            _ = self.items.remove(index);
+    pub fn remove(&mut self, index: usize) -> Result<ListItem, ListError> {
+        self.check_index(index)?;
+        Ok(self.items.remove(index).expect("index was checked"))
+    }
+
+    /// Removes the item at the specified index and returns it, replacing it with the last item.
+    ///
+    /// This does not preserve ordering, but is O(1). Returns
+    /// `ListError::IndexOutOfBounds` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// list.insert(3);
+    /// assert_eq!(list.swap_remove(0).unwrap().to_string(), "1");
+    /// assert_eq!(list.to_string(), "[3, 2]");
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Result<ListItem, ListError> {
+        self.check_index(index)?;
+        Ok(self
+            .items
+            .swap_remove_back(index)
+            .expect("index was checked"))
+    }
+
+    /// Removes the item at the specified index and returns it by value if the type matches.
+    ///
+    /// The list is left untouched on failure. Returns
+    /// `ListError::IndexOutOfBounds` if the index is out of bounds and
+    /// `ListError::TypeMismatch` if the item holds a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert("owned".to_string());
+    /// assert!(list.take::<i32>(0).is_err());
+    /// assert_eq!(list.take::<String>(0), Ok("owned".to_string()));
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn take<T: 'static>(&mut self, index: usize) -> Result<T, ListError> {
+        self.check_index(index)?;
+        let item = &self.items[index];
+        if !item.as_any().is::<T>() {
+            return Err(item.type_mismatch::<T>());
+        }
+        let item = self.items.remove(index).expect("index was checked");
+        match item.into_any().downcast::<T>() {
+            Ok(value) => Ok(*value),
+            Err(_) => unreachable!("the type was checked before removing the item"),
         }
     }
 
+    /// Removes the last item and returns it, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// assert_eq!(list.pop().map(|item| item.to_string()), Some("2".to_string()));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn pop(&mut self) -> Option<ListItem> {
+        self.items.pop_back()
+    }
+
+    /// Removes the first item and returns it, or `None` if the list is empty.
+    ///
+    /// This is amortized O(1), like `List::pop`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// assert_eq!(list.pop_front().map(|item| item.to_string()), Some("1".to_string()));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<ListItem> {
+        self.items.pop_front()
+    }
+
     /// Retrieves a reference to the item at the specified index if the type matches.
     ///
     /// Returns `None` if the index is out of bounds or the type doesn't match.
@@ -101,15 +460,19 @@ impl List {
     /// ```
     /// let mut list = rusty_list::List::new();
     /// list.insert(42);
+    /// list.insert(true);
+    /// list.insert(None::<i32>);
     /// assert_eq!(list.get::<i32>(0), Some(&42));
+    /// assert_eq!(list.get::<bool>(1), Some(&true));
+    /// assert_eq!(list.get::<()>(2), Some(&()));
+    ///
+    /// // Integers are never widened: the requested type must match exactly.
+    /// list.insert(7u64);
+    /// assert_eq!(list.get::<u64>(3), Some(&7));
+    /// assert_eq!(list.get::<i64>(0), None);
     /// ```
     pub fn get<T: 'static>(&self, index: usize) -> Option<&T> {
-        match self.items.get(index)? {
-            ListItem::Int(value) => value as &dyn std::any::Any,
-            ListItem::Str(value) => value as &dyn std::any::Any,
-            ListItem::Float(value) => value as &dyn std::any::Any,
-        }
-        .downcast_ref::<T>()
+        self.items.get(index)?.as_any().downcast_ref::<T>()
     }
 
     /// Retrieves a mutable reference to the item at the specified index if the type matches.
@@ -127,12 +490,174 @@ impl List {
     /// assert_eq!(list.get::<i32>(0), Some(&43));
     /// ```
     pub fn get_mut<T: 'static>(&mut self, index: usize) -> Option<&mut T> {
-        match self.items.get_mut(index)? {
-            ListItem::Int(value) => value as &mut dyn std::any::Any,
-            ListItem::Str(value) => value as &mut dyn std::any::Any,
-            ListItem::Float(value) => value as &mut dyn std::any::Any,
-        }
-        .downcast_mut::<T>()
+        self.items.get_mut(index)?.as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Retrieves a reference to the item at the specified index, reporting why it failed.
+    ///
+    /// Returns `ListError::IndexOutOfBounds` if the index is out of bounds and
+    /// `ListError::TypeMismatch` if the item holds a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{List, ListError};
+    ///
+    /// let mut list = List::new();
+    /// list.insert(42);
+    /// assert_eq!(list.try_get::<i32>(0), Ok(&42));
+    /// assert_eq!(
+    ///     list.try_get::<i32>(1),
+    ///     Err(ListError::IndexOutOfBounds { index: 1, len: 1 })
+    /// );
+    /// assert!(matches!(
+    ///     list.try_get::<f64>(0),
+    ///     Err(ListError::TypeMismatch { .. })
+    /// ));
+    /// ```
+    pub fn try_get<T: 'static>(&self, index: usize) -> Result<&T, ListError> {
+        self.check_index(index)?;
+        let item = &self.items[index];
+        item.as_any()
+            .downcast_ref::<T>()
+            .ok_or_else(|| item.type_mismatch::<T>())
+    }
+
+    /// Retrieves a mutable reference to the item at the specified index, reporting why it failed.
+    ///
+    /// Returns `ListError::IndexOutOfBounds` if the index is out of bounds and
+    /// `ListError::TypeMismatch` if the item holds a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(42);
+    /// *list.try_get_mut::<i32>(0).unwrap() += 1;
+    /// assert_eq!(list.get::<i32>(0), Some(&43));
+    /// assert!(list.try_get_mut::<String>(0).is_err());
+    /// ```
+    pub fn try_get_mut<T: 'static>(&mut self, index: usize) -> Result<&mut T, ListError> {
+        self.check_index(index)?;
+        let item = &mut self.items[index];
+        let error = item.type_mismatch::<T>();
+        item.as_any_mut().downcast_mut::<T>().ok_or(error)
+    }
+
+    /// Retrieves a reference to the nested list at the specified index.
+    ///
+    /// Returns `None` if the index is out of bounds or the item is not a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::List;
+    ///
+    /// let mut row = List::new();
+    /// row.insert(1);
+    /// let mut table = List::new();
+    /// table.insert(row);
+    /// assert_eq!(table.get_list(0).and_then(|row| row.get::<i32>(0)), Some(&1));
+    /// ```
+    pub fn get_list(&self, index: usize) -> Option<&List> {
+        self.get::<List>(index)
+    }
+
+    /// Retrieves a mutable reference to the nested list at the specified index.
+    ///
+    /// Returns `None` if the index is out of bounds or the item is not a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::List;
+    ///
+    /// let mut table = List::new();
+    /// table.insert(List::new());
+    /// table.get_list_mut(0).unwrap().insert("cell".to_string());
+    /// assert_eq!(table.to_string(), "[[cell]]");
+    /// ```
+    pub fn get_list_mut(&mut self, index: usize) -> Option<&mut List> {
+        self.get_mut::<List>(index)
+    }
+
+    /// Retrieves a reference to the map at the specified index.
+    ///
+    /// Returns `None` if the index is out of bounds or the item is not a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{List, ListMap};
+    ///
+    /// let mut record = ListMap::new();
+    /// record.insert("id", 7);
+    /// record.insert("name", "seven".to_string());
+    /// let mut list = List::new();
+    /// list.insert(record);
+    /// assert_eq!(list.get_map(0).and_then(|m| m.get::<i32>("id")), Some(&7));
+    /// assert_eq!(list.to_string(), "[{id: 7, name: seven}]");
+    /// ```
+    pub fn get_map(&self, index: usize) -> Option<&ListMap> {
+        self.get::<ListMap>(index)
+    }
+
+    /// Retrieves a mutable reference to the map at the specified index.
+    ///
+    /// Returns `None` if the index is out of bounds or the item is not a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{List, ListMap};
+    ///
+    /// let mut list = List::new();
+    /// list.insert(ListMap::new());
+    /// list.get_map_mut(0).unwrap().insert("enabled", true);
+    /// assert_eq!(list.to_string(), "[{enabled: true}]");
+    /// ```
+    pub fn get_map_mut(&mut self, index: usize) -> Option<&mut ListMap> {
+        self.get_mut::<ListMap>(index)
+    }
+
+    /// Retrieves the item at position `n` counting from the end of the list.
+    ///
+    /// `get_from_end(0)` is the last item, like `list[-1]` in Python. Returns
+    /// `None` if `n` is not smaller than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItem};
+    ///
+    /// let list = list![1, 2, 3];
+    /// assert_eq!(list.get_from_end(0), Some(&ListItem::Int(3)));
+    /// assert_eq!(list.get_from_end(2), Some(&ListItem::Int(1)));
+    /// assert_eq!(list.get_from_end(3), None);
+    /// ```
+    pub fn get_from_end(&self, n: usize) -> Option<&ListItem> {
+        let index = self.items.len().checked_sub(n)?.checked_sub(1)?;
+        self.items.get(index)
+    }
+
+    /// Returns a borrowed view of the items in the range, without copying them.
+    ///
+    /// Returns `ListError::InvalidRange` if the range is reversed or extends
+    /// past the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![0, 1, 2, 3, 4, 5];
+    /// let window = list.slice(2..5).unwrap();
+    /// assert_eq!(window.len(), 3);
+    /// assert_eq!(window.get::<i32>(0), Some(&2));
+    /// assert_eq!(window.iter().count(), 3);
+    /// assert!(list.slice(4..7).is_err());
+    /// ```
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<ListSlice<'_>, ListError> {
+        let range = self.check_range(range)?;
+        Ok(ListSlice::new(self, range))
     }
 
     /// Returns an iterator over the items in the list.
@@ -150,9 +675,255 @@ impl List {
         ListIter {
             list: self,
             index: 0,
+            end: self.items.len(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the items in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{List, ListItem};
+    ///
+    /// let mut list = List::new();
+    /// list.insert(1);
+    /// list.insert(2.5);
+    /// for item in list.iter_mut() {
+    ///     if let ListItem::Int(value) = item {
+    ///         *value *= 10;
+    ///     }
+    /// }
+    /// assert_eq!(list.to_string(), "[10, 2.5]");
+    /// ```
+    pub fn iter_mut(&mut self) -> ListIterMut<'_> {
+        ListIterMut {
+            inner: self.items.iter_mut(),
         }
     }
 
+    /// Returns an iterator over the items of type `T`, skipping all other items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert("skipped".to_string());
+    /// list.insert(2);
+    /// assert_eq!(list.iter_of::<i32>().sum::<i32>(), 3);
+    /// ```
+    pub fn iter_of<T: 'static>(&self) -> impl Iterator<Item = &T> + '_ {
+        self.items
+            .iter()
+            .filter_map(|item| item.as_any().downcast_ref::<T>())
+    }
+
+    /// Returns an iterator over mutable references to the items of type `T`, skipping all other items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1.5);
+    /// list.insert(7);
+    /// for value in list.iter_of_mut::<f64>() {
+    ///     *value *= 2.0;
+    /// }
+    /// assert_eq!(list.to_string(), "[3, 7]");
+    /// ```
+    pub fn iter_of_mut<T: 'static>(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.items
+            .iter_mut()
+            .filter_map(|item| item.as_any_mut().downcast_mut::<T>())
+    }
+
+    /// Returns an iterator over the items of type `T` together with their index in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = rusty_list::List::new();
+    /// list.insert(1);
+    /// list.insert("two".to_string());
+    /// list.insert("three".to_string());
+    /// let strings: Vec<_> = list.iter_of_indexed::<String>().collect();
+    /// assert_eq!(strings, [(1, &"two".to_string()), (2, &"three".to_string())]);
+    /// ```
+    pub fn iter_of_indexed<T: 'static>(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| Some((index, item.as_any().downcast_ref::<T>()?)))
+    }
+
+    /// Returns `true` if the list contains an item equal to `value`.
+    ///
+    /// `value` can be a `ListItem`, compared with `ListItem` equality, or a
+    /// value of a stored type, compared with that type's `PartialEq` against
+    /// the items of the same type. A `&str` is compared with the `Str` items,
+    /// as `list!` and `From<&str>` store string slices as `String`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItem};
+    ///
+    /// let list = list![1, "a", 2.5];
+    /// assert!(list.contains(&"a"));
+    /// assert!(list.contains(&"a".to_string()));
+    /// assert!(list.contains(&ListItem::Float(2.5)));
+    /// assert!(!list.contains(&1u8));
+    /// ```
+    pub fn contains<T: PartialEq + 'static>(&self, value: &T) -> bool {
+        self.items.iter().any(|item| item.matches(value))
+    }
+
+    /// Returns the index of the first item equal to `value`, see `List::contains`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, "b", 2, 1, "b"];
+    /// assert_eq!(list.position(&1), Some(0));
+    /// assert_eq!(list.position(&"b"), Some(1));
+    /// assert_eq!(list.position(&3), None);
+    /// ```
+    pub fn position<T: PartialEq + 'static>(&self, value: &T) -> Option<usize> {
+        self.items.iter().position(|item| item.matches(value))
+    }
+
+    /// Returns the index of the last item equal to `value`, see `List::contains`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, "b", 2, 1, "b"];
+    /// assert_eq!(list.rposition(&1), Some(3));
+    /// assert_eq!(list.rposition(&"b"), Some(4));
+    /// ```
+    pub fn rposition<T: PartialEq + 'static>(&self, value: &T) -> Option<usize> {
+        self.items.iter().rposition(|item| item.matches(value))
+    }
+
+    /// Returns the first item of type `T` that satisfies the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, "long word", 20, "short"];
+    /// assert_eq!(list.find::<i32>(|n| *n > 10), Some(&20));
+    /// assert_eq!(list.find::<String>(|s| s.len() < 6), Some(&"short".to_string()));
+    /// ```
+    pub fn find<T: 'static>(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<&T> {
+        self.iter_of::<T>().find(|value| predicate(value))
+    }
+
+    /// Returns an iterator over all items of type `T` that satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, 2.5, 3, 4];
+    /// let odd: Vec<_> = list.find_all::<i32>(|n| n % 2 == 1).collect();
+    /// assert_eq!(odd, [&1, &3]);
+    /// ```
+    pub fn find_all<'a, T: 'static>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter_of::<T>().filter(move |value| predicate(value))
+    }
+
+    /// Binary searches a list sorted with `List::sort` for the item.
+    ///
+    /// Returns `Ok` with the index of a matching item, or `Err` with the index
+    /// where the item could be inserted to keep the list sorted. The result is
+    /// unspecified if the list is not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItem};
+    ///
+    /// let mut list = list!["b", 3, 1.5, ()];
+    /// list.sort();
+    /// assert_eq!(list.binary_search(&ListItem::Float(1.5)), Ok(1));
+    /// assert_eq!(list.binary_search(&ListItem::from(2)), Err(2));
+    /// ```
+    pub fn binary_search(&self, value: &ListItem) -> Result<usize, usize> {
+        self.items.binary_search(value)
+    }
+
+    /// Sorts the list using the total order of `ListItem`.
+    ///
+    /// Items that compare equal are identical, so this uses an unstable sort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let mut list = list!["b", 2.5, (), 3, true, "a", 1];
+    /// list.sort();
+    /// assert_eq!(list, list![(), true, 1, 2.5, 3, "a", "b"]);
+    /// ```
+    pub fn sort(&mut self) {
+        self.items.make_contiguous().sort_unstable();
+    }
+
+    /// Sorts the list using the total order of `ListItem`, preserving the order of equal items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let mut list = list![3, 1, 2];
+    /// list.sort_stable();
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    pub fn sort_stable(&mut self) {
+        self.items.make_contiguous().sort();
+    }
+
+    /// Sorts the list with a comparator function, preserving the order of equal items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let mut list = list![1, 3, 2];
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&ListItem, &ListItem) -> Ordering,
+    {
+        self.items.make_contiguous().sort_by(compare);
+    }
+
+    /// Sorts the list with a key extraction function, preserving the order of equal items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let mut list = list!["ccc", "a", "bb"];
+    /// list.sort_by_key(|item| item.to_string().len());
+    /// assert_eq!(list, list!["a", "bb", "ccc"]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&ListItem) -> K,
+        K: Ord,
+    {
+        self.items.make_contiguous().sort_by_key(f);
+    }
+
     /// Returns the number of items in the list.
     ///
     /// # Examples
@@ -183,6 +954,45 @@ impl List {
     pub fn clear(&mut self) {
         self.items.clear();
     }
+
+    fn check_index(&self, index: usize) -> Result<(), ListError> {
+        if index < self.items.len() {
+            Ok(())
+        } else {
+            Err(ListError::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            })
+        }
+    }
+
+    fn check_range<R: RangeBounds<usize>>(&self, range: R) -> Result<Range<usize>, ListError> {
+        check_range(range, self.items.len())
+    }
+}
+
+/// Resolves `range` against a sequence of length `len`.
+fn check_range<R: RangeBounds<usize>>(range: R, len: usize) -> Result<Range<usize>, ListError> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return Err(ListError::InvalidRange { start, end, len });
+    }
+    Ok(start..end)
+}
+
+/// Returns a mutable reference to the unit value that `Null` items hold.
+pub(crate) fn null_mut() -> &'static mut () {
+    // Leaking a zero-sized value does not allocate.
+    Box::leak(Box::new(()))
 }
 
 impl Default for List {
@@ -191,22 +1001,212 @@ impl Default for List {
     }
 }
 
+/// # Examples
+///
+/// ```
+/// use rusty_list::List;
+///
+/// let list: List = (1..=3).collect();
+/// assert_eq!(list.to_string(), "[1, 2, 3]");
+/// ```
+impl<T: Into<ListItem>> FromIterator<T> for List {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List {
+            items: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// let mut list = rusty_list::list![1];
+/// list.extend(["a", "b"]);
+/// assert_eq!(list.to_string(), "[1, a, b]");
+/// ```
+impl<T: Into<ListItem>> Extend<T> for List {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+/// Creates a `List` from values of any type that converts into a `ListItem`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::list;
+///
+/// let list = list![42, "a", 2.5, list![true, 'c']];
+/// assert_eq!(list.len(), 4);
+/// assert_eq!(list.get::<String>(1), Some(&"a".to_string()));
+/// assert_eq!(list.to_string(), "[42, a, 2.5, [true, c]]");
+/// assert!(list![].is_empty());
+/// ```
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::List::new()
+    };
+    ($($value:expr),+ $(,)?) => {{
+        let mut list = $crate::List::new();
+        $(list.insert($value);)+
+        list
+    }};
+}
+
+impl ListItem {
+    fn as_any(&self) -> &dyn Any {
+        match self {
+            ListItem::Int(value) => value,
+            ListItem::I8(value) => value,
+            ListItem::I16(value) => value,
+            ListItem::I64(value) => value,
+            ListItem::I128(value) => value,
+            ListItem::Isize(value) => value,
+            ListItem::U8(value) => value,
+            ListItem::U16(value) => value,
+            ListItem::U32(value) => value,
+            ListItem::U64(value) => value,
+            ListItem::U128(value) => value,
+            ListItem::Usize(value) => value,
+            ListItem::Str(value) => value,
+            ListItem::Float(value) => value,
+            ListItem::Bool(value) => value,
+            ListItem::Char(value) => value,
+            ListItem::Null => &(),
+            ListItem::List(value) => value,
+            ListItem::Map(value) => value,
+            ListItem::Custom(value) => value.as_ref(),
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        match self {
+            ListItem::Int(value) => value,
+            ListItem::I8(value) => value,
+            ListItem::I16(value) => value,
+            ListItem::I64(value) => value,
+            ListItem::I128(value) => value,
+            ListItem::Isize(value) => value,
+            ListItem::U8(value) => value,
+            ListItem::U16(value) => value,
+            ListItem::U32(value) => value,
+            ListItem::U64(value) => value,
+            ListItem::U128(value) => value,
+            ListItem::Usize(value) => value,
+            ListItem::Str(value) => value,
+            ListItem::Float(value) => value,
+            ListItem::Bool(value) => value,
+            ListItem::Char(value) => value,
+            ListItem::Null => null_mut(),
+            ListItem::List(value) => value,
+            ListItem::Map(value) => value,
+            ListItem::Custom(value) => value.as_mut(),
+        }
+    }
+
+    fn into_any(self) -> Box<dyn Any> {
+        match self {
+            ListItem::Int(value) => Box::new(value),
+            ListItem::I8(value) => Box::new(value),
+            ListItem::I16(value) => Box::new(value),
+            ListItem::I64(value) => Box::new(value),
+            ListItem::I128(value) => Box::new(value),
+            ListItem::Isize(value) => Box::new(value),
+            ListItem::U8(value) => Box::new(value),
+            ListItem::U16(value) => Box::new(value),
+            ListItem::U32(value) => Box::new(value),
+            ListItem::U64(value) => Box::new(value),
+            ListItem::U128(value) => Box::new(value),
+            ListItem::Usize(value) => Box::new(value),
+            ListItem::Str(value) => Box::new(value),
+            ListItem::Float(value) => Box::new(value),
+            ListItem::Bool(value) => Box::new(value),
+            ListItem::Char(value) => Box::new(value),
+            ListItem::Null => Box::new(()),
+            ListItem::List(value) => Box::new(value),
+            ListItem::Map(value) => Box::new(value),
+            ListItem::Custom(value) => value,
+        }
+    }
+
+    /// Compares the item with a `ListItem`, a `&str` or a value of a stored type.
+    fn matches<T: PartialEq + 'static>(&self, value: &T) -> bool {
+        let value_any = value as &dyn Any;
+        if let Some(item) = value_any.downcast_ref::<ListItem>() {
+            return self == item;
+        }
+        if let Some(text) = value_any.downcast_ref::<&str>() {
+            return matches!(self, ListItem::Str(value) if value == text);
+        }
+        self.as_any().downcast_ref::<T>() == Some(value)
+    }
+
+    fn type_mismatch<T: 'static>(&self) -> ListError {
+        ListError::TypeMismatch {
+            expected: std::any::type_name::<T>(),
+            found: self.type_name(),
+        }
+    }
+}
+
 // Implementation of From for different types
-impl From<i32> for ListItem {
-    fn from(value: i32) -> Self {
-        ListItem::Int(value)
+macro_rules! impl_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for ListItem {
+                fn from(value: $ty) -> Self {
+                    ListItem::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    i8 => I8,
+    i16 => I16,
+    i32 => Int,
+    i64 => I64,
+    i128 => I128,
+    isize => Isize,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    usize => Usize,
+    String => Str,
+    f64 => Float,
+    bool => Bool,
+    char => Char,
+    List => List,
+    ListMap => Map,
+}
+
+impl<T: ListValue> From<T> for ListItem {
+    fn from(value: T) -> Self {
+        ListItem::Custom(Box::new(value))
     }
 }
 
-impl From<String> for ListItem {
-    fn from(value: String) -> Self {
-        ListItem::Str(value)
+impl From<&str> for ListItem {
+    fn from(value: &str) -> Self {
+        ListItem::Str(value.to_string())
     }
 }
 
-impl From<f64> for ListItem {
-    fn from(value: f64) -> Self {
-        ListItem::Float(value)
+impl From<()> for ListItem {
+    fn from(_: ()) -> Self {
+        ListItem::Null
+    }
+}
+
+/// `None` becomes `ListItem::Null`, `Some(value)` is converted as `value`.
+impl<T: Into<ListItem>> From<Option<T>> for ListItem {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(ListItem::Null, Into::into)
     }
 }
 
@@ -215,9 +1215,139 @@ impl fmt::Display for ListItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ListItem::Int(val) => write!(f, "{val}"),
+            ListItem::I8(val) => write!(f, "{val}"),
+            ListItem::I16(val) => write!(f, "{val}"),
+            ListItem::I64(val) => write!(f, "{val}"),
+            ListItem::I128(val) => write!(f, "{val}"),
+            ListItem::Isize(val) => write!(f, "{val}"),
+            ListItem::U8(val) => write!(f, "{val}"),
+            ListItem::U16(val) => write!(f, "{val}"),
+            ListItem::U32(val) => write!(f, "{val}"),
+            ListItem::U64(val) => write!(f, "{val}"),
+            ListItem::U128(val) => write!(f, "{val}"),
+            ListItem::Usize(val) => write!(f, "{val}"),
             ListItem::Str(val) => write!(f, "{val}"),
             ListItem::Float(val) => write!(f, "{val}"),
+            ListItem::Bool(val) => write!(f, "{val}"),
+            ListItem::Char(val) => write!(f, "{val}"),
+            ListItem::Null => write!(f, "null"),
+            ListItem::List(val) => write!(f, "{val}"),
+            ListItem::Map(val) => write!(f, "{val}"),
+            ListItem::Custom(val) => write!(f, "{val}"),
+        }
+    }
+}
+
+impl PartialEq for ListItem {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ListItem::Int(a), ListItem::Int(b)) => a == b,
+            (ListItem::I8(a), ListItem::I8(b)) => a == b,
+            (ListItem::I16(a), ListItem::I16(b)) => a == b,
+            (ListItem::I64(a), ListItem::I64(b)) => a == b,
+            (ListItem::I128(a), ListItem::I128(b)) => a == b,
+            (ListItem::Isize(a), ListItem::Isize(b)) => a == b,
+            (ListItem::U8(a), ListItem::U8(b)) => a == b,
+            (ListItem::U16(a), ListItem::U16(b)) => a == b,
+            (ListItem::U32(a), ListItem::U32(b)) => a == b,
+            (ListItem::U64(a), ListItem::U64(b)) => a == b,
+            (ListItem::U128(a), ListItem::U128(b)) => a == b,
+            (ListItem::Usize(a), ListItem::Usize(b)) => a == b,
+            (ListItem::Str(a), ListItem::Str(b)) => a == b,
+            (ListItem::Float(a), ListItem::Float(b)) => a.to_bits() == b.to_bits(),
+            (ListItem::Bool(a), ListItem::Bool(b)) => a == b,
+            (ListItem::Char(a), ListItem::Char(b)) => a == b,
+            (ListItem::Null, ListItem::Null) => true,
+            (ListItem::List(a), ListItem::List(b)) => a == b,
+            (ListItem::Map(a), ListItem::Map(b)) => a == b,
+            (ListItem::Custom(a), ListItem::Custom(_)) => a.eq_value(other.as_any()),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ListItem {}
+
+impl Hash for ListItem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            ListItem::Int(value) => value.hash(state),
+            ListItem::I8(value) => value.hash(state),
+            ListItem::I16(value) => value.hash(state),
+            ListItem::I64(value) => value.hash(state),
+            ListItem::I128(value) => value.hash(state),
+            ListItem::Isize(value) => value.hash(state),
+            ListItem::U8(value) => value.hash(state),
+            ListItem::U16(value) => value.hash(state),
+            ListItem::U32(value) => value.hash(state),
+            ListItem::U64(value) => value.hash(state),
+            ListItem::U128(value) => value.hash(state),
+            ListItem::Usize(value) => value.hash(state),
+            ListItem::Str(value) => value.hash(state),
+            ListItem::Float(value) => value.to_bits().hash(state),
+            ListItem::Bool(value) => value.hash(state),
+            ListItem::Char(value) => value.hash(state),
+            ListItem::Null => {}
+            ListItem::List(value) => value.hash(state),
+            ListItem::Map(value) => value.hash(state),
+            ListItem::Custom(value) => {
+                self.as_any().type_id().hash(state);
+                value.hash_value(state);
+            }
+        }
+    }
+}
+
+/// Returns the item at the index.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds, use `List::get` or `List::try_get`
+/// for checked access.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{list, ListItem};
+///
+/// let mut list = list![1, "two"];
+/// assert_eq!(list[1], ListItem::from("two"));
+/// list[0] = ListItem::Float(1.5);
+/// assert_eq!(list, list![1.5, "two"]);
+/// ```
+impl Index<usize> for List {
+    type Output = ListItem;
+
+    fn index(&self, index: usize) -> &ListItem {
+        &self.items[index]
+    }
+}
+
+impl IndexMut<usize> for List {
+    fn index_mut(&mut self, index: usize) -> &mut ListItem {
+        &mut self.items[index]
+    }
+}
+
+// Implementation of Debug for List
+impl fmt::Debug for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.items).finish()
+    }
+}
+
+// Implementation of Display for List
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{item}")?;
         }
+        write!(f, "]")
     }
 }
 
@@ -225,13 +1355,14 @@ impl fmt::Display for ListItem {
 pub struct ListIter<'a> {
     list: &'a List,
     index: usize,
+    end: usize,
 }
 
 impl<'a> Iterator for ListIter<'a> {
     type Item = &'a ListItem;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.list.len() {
+        if self.index < self.end {
             let item = &self.list.items[self.index];
             self.index += 1;
             Some(item)
@@ -239,4 +1370,130 @@ impl<'a> Iterator for ListIter<'a> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ListIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(&self.list.items[self.end])
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for ListIter<'_> {}
+
+impl FusedIterator for ListIter<'_> {}
+
+// Mutable iterator for List
+pub struct ListIterMut<'a> {
+    inner: std::collections::vec_deque::IterMut<'a, ListItem>,
+}
+
+impl<'a> Iterator for ListIterMut<'a> {
+    type Item = &'a mut ListItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ListIterMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for ListIterMut<'_> {}
+
+impl FusedIterator for ListIterMut<'_> {}
+
+// Owning iterator for List
+pub struct ListIntoIter {
+    inner: std::collections::vec_deque::IntoIter<ListItem>,
+}
+
+impl Iterator for ListIntoIter {
+    type Item = ListItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ListIntoIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for ListIntoIter {}
+
+impl FusedIterator for ListIntoIter {}
+
+/// Consumes the list, yielding its items in order.
+///
+/// # Examples
+///
+/// ```
+/// let mut list = rusty_list::List::new();
+/// list.insert(1);
+/// list.insert("two".to_string());
+/// let items: Vec<String> = list.into_iter().rev().map(|item| item.to_string()).collect();
+/// assert_eq!(items, ["two", "1"]);
+/// ```
+impl IntoIterator for List {
+    type Item = ListItem;
+    type IntoIter = ListIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ListIntoIter {
+            inner: self.items.into_iter(),
+        }
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// let mut list = rusty_list::List::new();
+/// list.insert(1);
+/// list.insert(2);
+/// let mut count = 0;
+/// for _item in &list {
+///     count += 1;
+/// }
+/// assert_eq!(count, list.iter().len());
+/// ```
+impl<'a> IntoIterator for &'a List {
+    type Item = &'a ListItem;
+    type IntoIter = ListIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut List {
+    type Item = &'a mut ListItem;
+    type IntoIter = ListIterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
@@ -0,0 +1,446 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::parse::{check_depth, narrowest_integer, parse_error, MAX_DEPTH};
+use crate::{List, ListError, ListItem, ListMap};
+
+impl List {
+    /// Returns the list in its literal syntax, which `str::parse` reads back
+    /// into an equal list.
+    ///
+    /// The syntax follows Rust literals:
+    ///
+    /// - `Int` is a plain integer such as `42`, and the other integer widths
+    ///   carry their type as a suffix, such as `42u8` or `-7i64`.
+    /// - `Float` always has a fraction or exponent, such as `1.0` or `1e300`,
+    ///   or is one of `NaN`, `inf` and `-inf`. A `NaN` other than `f64::NAN`
+    ///   keeps its bits as `NaN(0x7ff8000000000001)`.
+    /// - `Str` is double-quoted and `Char` single-quoted, with `\\`, `\"`,
+    ///   `\'`, `\n`, `\r`, `\t`, `\0` and `\u{..}` escapes.
+    /// - `Bool` is `true` or `false`, and `Null` is `null`.
+    /// - Nested lists are written `[a, b]` and maps `{"key": value}`.
+    ///
+    /// Returns `ListError::Unsupported` if the list contains a custom value,
+    /// which has no literal syntax, and `ListError::TooDeep` if lists and maps
+    /// nest more than 128 levels deep, which parsing would reject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, List, ListError};
+    ///
+    /// let list = list![42, "say \"hi\", bye", 3.14, 1.0, 7u8, 'x', ()];
+    /// let literal = list.to_literal().unwrap();
+    /// assert_eq!(literal, r#"[42, "say \"hi\", bye", 3.14, 1.0, 7u8, 'x', null]"#);
+    /// assert_eq!(literal.parse::<List>(), Ok(list));
+    ///
+    /// let mut deep = list![];
+    /// for _ in 0..128 {
+    ///     deep = list![deep];
+    /// }
+    /// assert_eq!(deep.to_literal(), Err(ListError::TooDeep { limit: 128 }));
+    /// ```
+    pub fn to_literal(&self) -> Result<String, ListError> {
+        let mut out = String::new();
+        write_list(&mut out, self, 0)?;
+        Ok(out)
+    }
+}
+
+/// Parses the literal syntax produced by `List::to_literal`.
+///
+/// Any whitespace may appear between tokens. Integers without a suffix become
+/// `Int` when they fit in an `i32`, otherwise the narrowest of `I64`, `U64`,
+/// `I128` and `U128`; `i32` and `f64` suffixes are accepted as well.
+///
+/// Returns `ListError::Parse` with the 1-based line and column of the first
+/// offending character if the input is not a single list literal, if an
+/// integer does not fit its suffix, or if lists and maps nest more than 128
+/// levels deep.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{list, List, ListError, ListMap};
+///
+/// let list: List = r#"[1, -2i64, 2.5, ["nested", '\u{1F600}'], {"k": true}]"#.parse().unwrap();
+/// let mut map = ListMap::new();
+/// map.insert("k", true);
+/// assert_eq!(list, list![1, -2i64, 2.5, list!["nested", '😀'], map]);
+///
+/// assert_eq!(
+///     "[1, 300u8]".parse::<List>(),
+///     Err(ListError::Parse { line: 1, column: 5 })
+/// );
+/// ```
+impl FromStr for List {
+    type Err = ListError;
+
+    fn from_str(input: &str) -> Result<List, ListError> {
+        let mut parser = Parser { input, pos: 0 };
+        parser.skip_whitespace();
+        if parser.peek() != Some('[') {
+            return Err(parser.error());
+        }
+        let list = parser.parse_list(0)?;
+        parser.skip_whitespace();
+        if parser.pos < input.len() {
+            return Err(parser.error());
+        }
+        Ok(list)
+    }
+}
+
+fn write_list(out: &mut String, list: &List, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    out.push('[');
+    for (i, item) in list.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_item(out, item, depth + 1)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+fn write_map(out: &mut String, map: &ListMap, depth: usize) -> Result<(), ListError> {
+    check_depth(depth)?;
+    out.push('{');
+    for (i, (key, value)) in map.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_quoted(out, key, '"').unwrap();
+        out.push_str(": ");
+        write_item(out, value, depth + 1)?;
+    }
+    out.push('}');
+    Ok(())
+}
+
+fn write_item(out: &mut String, item: &ListItem, depth: usize) -> Result<(), ListError> {
+    match item {
+        ListItem::List(value) => write_list(out, value, depth),
+        ListItem::Map(value) => write_map(out, value, depth),
+        ListItem::Custom(_) => Err(ListError::Unsupported {
+            type_name: item.type_name(),
+            operation: "the literal syntax",
+        }),
+        _ => {
+            // Writing to a `String` cannot fail.
+            write_scalar(out, item).unwrap();
+            Ok(())
+        }
+    }
+}
+
+fn write_scalar(out: &mut String, item: &ListItem) -> fmt::Result {
+    match item {
+        ListItem::I8(value) => write!(out, "{value}i8"),
+        ListItem::I16(value) => write!(out, "{value}i16"),
+        ListItem::I64(value) => write!(out, "{value}i64"),
+        ListItem::I128(value) => write!(out, "{value}i128"),
+        ListItem::Isize(value) => write!(out, "{value}isize"),
+        ListItem::U8(value) => write!(out, "{value}u8"),
+        ListItem::U16(value) => write!(out, "{value}u16"),
+        ListItem::U32(value) => write!(out, "{value}u32"),
+        ListItem::U64(value) => write!(out, "{value}u64"),
+        ListItem::U128(value) => write!(out, "{value}u128"),
+        ListItem::Usize(value) => write!(out, "{value}usize"),
+        ListItem::Float(value) if value.is_nan() && value.to_bits() != f64::NAN.to_bits() => {
+            write!(out, "NaN({:#018x})", value.to_bits())
+        }
+        // `Debug` keeps the fraction of integral floats (`1.0`) and prints the
+        // shortest text that parses back to the same bits.
+        ListItem::Float(value) => write!(out, "{value:?}"),
+        ListItem::Str(value) => write_quoted(out, value, '"'),
+        ListItem::Char(value) => write_quoted(out, value.encode_utf8(&mut [0; 4]), '\''),
+        // `Int`, `Bool` and `Null`.
+        _ => write!(out, "{item}"),
+    }
+}
+
+fn write_quoted(out: &mut String, value: &str, quote: char) -> fmt::Result {
+    out.push(quote);
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\0' => out.push_str("\\0"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c.is_control() => write!(out, "\\u{{{:x}}}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push(quote);
+    Ok(())
+}
+
+/// A recursive descent parser over the characters of a literal.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Returns a parse error pointing at the current position.
+    fn error(&self) -> ListError {
+        parse_error(self.input, self.pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ListError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consumes the characters while `predicate` holds and returns them.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<ListItem, ListError> {
+        match self.peek() {
+            Some('[') => self.parse_list(depth).map(ListItem::List),
+            Some('{') => self.parse_map(depth).map(ListItem::Map),
+            Some('"') => self.parse_quoted('"').map(ListItem::Str),
+            Some('\'') => self.parse_char(),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some(c) if c.is_ascii_alphabetic() => self.parse_keyword(),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_list(&mut self, depth: usize) -> Result<List, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.expect('[')?;
+        let mut list = List::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(list);
+        }
+        loop {
+            self.skip_whitespace();
+            list.insert(self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => {}
+                Some(']') => return Ok(list),
+                _ => return Err(parse_error(self.input, self.pos - 1)),
+            }
+        }
+    }
+
+    fn parse_map(&mut self, depth: usize) -> Result<ListMap, ListError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.expect('{')?;
+        let mut map = ListMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(map);
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error());
+            }
+            let key = self.parse_quoted('"')?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            map.insert(key, self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => {}
+                Some('}') => return Ok(map),
+                _ => return Err(parse_error(self.input, self.pos - 1)),
+            }
+        }
+    }
+
+    fn parse_quoted(&mut self, quote: char) -> Result<String, ListError> {
+        self.expect(quote)?;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some(c) if c == quote => {
+                    self.bump();
+                    return Ok(value);
+                }
+                Some('\\') => value.push(self.parse_escape()?),
+                Some(c) => {
+                    self.bump();
+                    value.push(c);
+                }
+                None => return Err(self.error()),
+            }
+        }
+    }
+
+    fn parse_char(&mut self) -> Result<ListItem, ListError> {
+        let start = self.pos;
+        let value = self.parse_quoted('\'')?;
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(ListItem::Char(c)),
+            _ => Err(parse_error(self.input, start)),
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, ListError> {
+        let start = self.pos;
+        self.expect('\\')?;
+        let c = match self.bump() {
+            Some('\\') => '\\',
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            Some('u') => {
+                self.expect('{')?;
+                let digits = self.take_while(|c| c.is_ascii_hexdigit());
+                let code = match digits.len() {
+                    1..=6 => u32::from_str_radix(digits, 16).unwrap(),
+                    _ => return Err(parse_error(self.input, start)),
+                };
+                self.expect('}')?;
+                return char::from_u32(code).ok_or_else(|| parse_error(self.input, start));
+            }
+            _ => return Err(parse_error(self.input, start)),
+        };
+        Ok(c)
+    }
+
+    fn parse_keyword(&mut self) -> Result<ListItem, ListError> {
+        let start = self.pos;
+        let item = match self.take_while(|c| c.is_ascii_alphanumeric() || c == '_') {
+            "null" => ListItem::Null,
+            "true" => ListItem::Bool(true),
+            "false" => ListItem::Bool(false),
+            "inf" => ListItem::Float(f64::INFINITY),
+            "NaN" if self.peek() == Some('(') => {
+                self.bump();
+                let bits = self
+                    .take_while(|c| c.is_ascii_alphanumeric())
+                    .strip_prefix("0x")
+                    .and_then(|digits| u64::from_str_radix(digits, 16).ok())
+                    .map(f64::from_bits)
+                    .filter(|value| value.is_nan())
+                    .ok_or_else(|| parse_error(self.input, start))?;
+                self.expect(')')?;
+                ListItem::Float(bits)
+            }
+            "NaN" => ListItem::Float(f64::NAN),
+            _ => return Err(parse_error(self.input, start)),
+        };
+        Ok(item)
+    }
+
+    fn parse_number(&mut self) -> Result<ListItem, ListError> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.bump();
+            if self.peek() == Some('i') {
+                return match self.parse_keyword()? {
+                    ListItem::Float(value) if value.is_infinite() => Ok(ListItem::Float(-value)),
+                    _ => Err(parse_error(self.input, start)),
+                };
+            }
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            return Err(self.error());
+        }
+        let mut is_float = false;
+        if self.peek() == Some('.') {
+            is_float = true;
+            self.bump();
+            if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+                return Err(self.error());
+            }
+        }
+        if let Some('e' | 'E') = self.peek() {
+            is_float = true;
+            self.bump();
+            if let Some('+' | '-') = self.peek() {
+                self.bump();
+            }
+            if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+                return Err(self.error());
+            }
+        }
+        let text = &self.input[start..self.pos];
+        let suffix_start = self.pos;
+        let suffix = self.take_while(|c| c.is_ascii_alphanumeric());
+
+        let out_of_range = || parse_error(self.input, start);
+        macro_rules! parse {
+            ($variant:ident) => {
+                if is_float {
+                    return Err(parse_error(self.input, suffix_start));
+                } else {
+                    text.parse()
+                        .map(ListItem::$variant)
+                        .map_err(|_| out_of_range())
+                }
+            };
+        }
+        match suffix {
+            "" if is_float => Ok(ListItem::Float(text.parse().unwrap())),
+            "" => match text.parse::<i128>() {
+                Ok(value) => Ok(narrowest_integer(value)),
+                Err(_) => text.parse().map(ListItem::U128).map_err(|_| out_of_range()),
+            },
+            "f64" => Ok(ListItem::Float(text.parse().unwrap())),
+            "i32" => parse!(Int),
+            "i8" => parse!(I8),
+            "i16" => parse!(I16),
+            "i64" => parse!(I64),
+            "i128" => parse!(I128),
+            "isize" => parse!(Isize),
+            "u8" => parse!(U8),
+            "u16" => parse!(U16),
+            "u32" => parse!(U32),
+            "u64" => parse!(U64),
+            "u128" => parse!(U128),
+            "usize" => parse!(Usize),
+            _ => Err(parse_error(self.input, suffix_start)),
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::ListItem;
+
+/// A string-keyed map of `ListItem`s that preserves insertion order.
+///
+/// Entries are kept in a vector in insertion order, next to a hash index from
+/// each key to its position, so lookups and inserts take constant time even
+/// for maps built from large untrusted input. Removing an entry shifts the
+/// ones after it and takes linear time. Two maps are equal when they hold
+/// equal entries in the same order, and maps are ordered lexicographically by
+/// their entries.
+#[derive(Clone)]
+pub struct ListMap {
+    entries: Vec<(String, ListItem)>,
+    index: HashMap<String, usize>,
+}
+
+impl ListMap {
+    /// Creates a new, empty `ListMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let map = rusty_list::ListMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        ListMap {
+            entries: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Inserts a value under the given key.
+    ///
+    /// If the key is already present its value is replaced in place, keeping
+    /// the original position, and the previous value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// assert!(map.insert("answer", 41).is_none());
+    /// assert!(map.insert("answer", 42).is_some());
+    /// assert_eq!(map.get::<i32>("answer"), Some(&42));
+    /// ```
+    pub fn insert<K: Into<String>, V: Into<ListItem>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<ListItem> {
+        let key = key.into();
+        let value = value.into();
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.index.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Retrieves a reference to the value under the key if the type matches.
+    ///
+    /// Returns `None` if the key is missing or the type doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// map.insert("name", "rusty".to_string());
+    /// assert_eq!(map.get::<String>("name"), Some(&"rusty".to_string()));
+    /// assert_eq!(map.get::<i32>("name"), None);
+    /// ```
+    pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
+        self.get_item(key)?.as_any().downcast_ref::<T>()
+    }
+
+    /// Retrieves a mutable reference to the value under the key if the type matches.
+    ///
+    /// Returns `None` if the key is missing or the type doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// map.insert("count", 1);
+    /// *map.get_mut::<i32>("count").unwrap() += 1;
+    /// assert_eq!(map.get::<i32>("count"), Some(&2));
+    /// ```
+    pub fn get_mut<T: 'static>(&mut self, key: &str) -> Option<&mut T> {
+        self.get_item_mut(key)?.as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Retrieves the item stored under the key, whatever its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// map.insert("flag", true);
+    /// assert_eq!(map.get_item("flag").map(|item| item.to_string()), Some("true".to_string()));
+    /// ```
+    pub fn get_item(&self, key: &str) -> Option<&ListItem> {
+        let index = self.position(key)?;
+        Some(&self.entries[index].1)
+    }
+
+    /// Retrieves a mutable reference to the item stored under the key, whatever its type.
+    pub fn get_item_mut(&mut self, key: &str) -> Option<&mut ListItem> {
+        let index = self.position(key)?;
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Removes the key from the map, returning its value if it was present.
+    ///
+    /// The remaining entries keep their relative order, which makes removal
+    /// linear in the number of entries after the removed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// assert!(map.remove("a").is_some());
+    /// assert!(map.remove("a").is_none());
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), ["b"]);
+    /// assert_eq!(map.get::<i32>("b"), Some(&2));
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<ListItem> {
+        let index = self.index.remove(key)?;
+        for (key, _) in &self.entries[index + 1..] {
+            *self.index.get_mut(key).unwrap() -= 1;
+        }
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns `true` if the map contains the key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Clears the map, removing all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    /// Returns an iterator over the entries in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut map = rusty_list::ListMap::new();
+    /// map.insert("x", 1);
+    /// map.insert("y", 2);
+    /// for (key, value) in map.iter() {
+    ///     println!("{key} = {value}");
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ListItem)> + '_ {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Returns an iterator over the keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.entries.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Returns an iterator over the values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &ListItem> + '_ {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+}
+
+impl Default for ListMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implementation of PartialEq for ListMap
+impl PartialEq for ListMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl Eq for ListMap {}
+
+// Implementation of PartialOrd for ListMap
+impl PartialOrd for ListMap {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Implementation of Ord for ListMap
+impl Ord for ListMap {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entries.cmp(&other.entries)
+    }
+}
+
+// Implementation of Hash for ListMap
+impl Hash for ListMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries.hash(state);
+    }
+}
+
+// Implementation of Debug for ListMap
+impl fmt::Debug for ListMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
+
+// Implementation of Display for ListMap
+impl fmt::Display for ListMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key}: {value}")?;
+        }
+        write!(f, "}}")
+    }
+}
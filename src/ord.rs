@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+use crate::ListItem;
+
+/// A numeric item widened to a representation that can be compared exactly.
+#[derive(Clone, Copy)]
+enum ExactNumber {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl ExactNumber {
+    fn cmp(self, other: ExactNumber) -> Ordering {
+        match (self, other) {
+            (ExactNumber::Signed(a), ExactNumber::Signed(b)) => a.cmp(&b),
+            (ExactNumber::Unsigned(a), ExactNumber::Unsigned(b)) => a.cmp(&b),
+            (ExactNumber::Float(a), ExactNumber::Float(b)) => a.total_cmp(&b),
+            (ExactNumber::Signed(a), ExactNumber::Unsigned(b)) => cmp_signed_unsigned(a, b),
+            (ExactNumber::Unsigned(a), ExactNumber::Signed(b)) => {
+                cmp_signed_unsigned(b, a).reverse()
+            }
+            (ExactNumber::Signed(a), ExactNumber::Float(b)) => cmp_signed_float(a, b),
+            (ExactNumber::Float(a), ExactNumber::Signed(b)) => cmp_signed_float(b, a).reverse(),
+            (ExactNumber::Unsigned(a), ExactNumber::Float(b)) => cmp_unsigned_float(a, b),
+            (ExactNumber::Float(a), ExactNumber::Unsigned(b)) => cmp_unsigned_float(b, a).reverse(),
+        }
+    }
+}
+
+fn cmp_signed_unsigned(a: i128, b: u128) -> Ordering {
+    match u128::try_from(a) {
+        Ok(a) => a.cmp(&b),
+        Err(_) => Ordering::Less,
+    }
+}
+
+// Rounding to `f64` is monotonic, so a strict inequality after rounding holds
+// for the exact values too. On a tie the float is integral and is compared
+// back in the integer domain, where 2^127 and 2^128 are out of range.
+fn cmp_signed_float(a: i128, b: f64) -> Ordering {
+    match (a as f64).total_cmp(&b) {
+        Ordering::Equal if b >= i128::MAX as f64 => Ordering::Less,
+        Ordering::Equal => a.cmp(&(b as i128)),
+        ordering => ordering,
+    }
+}
+
+fn cmp_unsigned_float(a: u128, b: f64) -> Ordering {
+    match (a as f64).total_cmp(&b) {
+        Ordering::Equal if b >= u128::MAX as f64 => Ordering::Less,
+        Ordering::Equal => a.cmp(&(b as u128)),
+        ordering => ordering,
+    }
+}
+
+impl ListItem {
+    /// The position of the item's type in the total order.
+    ///
+    /// All numeric variants share a rank and are compared by value.
+    fn type_rank(&self) -> u8 {
+        match self {
+            ListItem::Null => 0,
+            ListItem::Bool(_) => 1,
+            ListItem::Int(_)
+            | ListItem::I8(_)
+            | ListItem::I16(_)
+            | ListItem::I64(_)
+            | ListItem::I128(_)
+            | ListItem::Isize(_)
+            | ListItem::U8(_)
+            | ListItem::U16(_)
+            | ListItem::U32(_)
+            | ListItem::U64(_)
+            | ListItem::U128(_)
+            | ListItem::Usize(_)
+            | ListItem::Float(_) => 2,
+            ListItem::Char(_) => 3,
+            ListItem::Str(_) => 4,
+            ListItem::List(_) => 5,
+            ListItem::Map(_) => 6,
+            ListItem::Custom(_) => 7,
+        }
+    }
+
+    /// Breaks ties between numerically equal items of different variants.
+    fn numeric_rank(&self) -> u8 {
+        match self {
+            ListItem::I8(_) => 0,
+            ListItem::I16(_) => 1,
+            ListItem::Int(_) => 2,
+            ListItem::I64(_) => 3,
+            ListItem::I128(_) => 4,
+            ListItem::Isize(_) => 5,
+            ListItem::U8(_) => 6,
+            ListItem::U16(_) => 7,
+            ListItem::U32(_) => 8,
+            ListItem::U64(_) => 9,
+            ListItem::U128(_) => 10,
+            ListItem::Usize(_) => 11,
+            _ => 12,
+        }
+    }
+
+    fn as_exact_number(&self) -> Option<ExactNumber> {
+        match *self {
+            ListItem::Int(value) => Some(ExactNumber::Signed(value.into())),
+            ListItem::I8(value) => Some(ExactNumber::Signed(value.into())),
+            ListItem::I16(value) => Some(ExactNumber::Signed(value.into())),
+            ListItem::I64(value) => Some(ExactNumber::Signed(value.into())),
+            ListItem::I128(value) => Some(ExactNumber::Signed(value)),
+            ListItem::Isize(value) => Some(ExactNumber::Signed(value as i128)),
+            ListItem::U8(value) => Some(ExactNumber::Unsigned(value.into())),
+            ListItem::U16(value) => Some(ExactNumber::Unsigned(value.into())),
+            ListItem::U32(value) => Some(ExactNumber::Unsigned(value.into())),
+            ListItem::U64(value) => Some(ExactNumber::Unsigned(value.into())),
+            ListItem::U128(value) => Some(ExactNumber::Unsigned(value)),
+            ListItem::Usize(value) => Some(ExactNumber::Unsigned(value as u128)),
+            ListItem::Float(value) => Some(ExactNumber::Float(value)),
+            _ => None,
+        }
+    }
+}
+
+/// Orders items first by type, `Null < Bool < numbers < Char < Str < List < Map < Custom`.
+///
+/// Numbers of every variant are compared by value, with `Float`s placed by
+/// `f64::total_cmp` (so `NaN` sorts after infinity). Numerically equal items of
+/// different variants, such as `Int(1)` and `Float(1.0)`, are ordered by
+/// variant, integers first, which keeps the order consistent with `Eq`. Lists
+/// and maps compare lexicographically. Custom values are grouped by type name
+/// and then compared with their own `Ord` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::ListItem;
+///
+/// assert!(ListItem::Null < ListItem::Bool(false));
+/// assert!(ListItem::Bool(true) < ListItem::Int(-5));
+/// assert!(ListItem::Int(2) < ListItem::Float(2.5));
+/// assert!(ListItem::Float(2.5) < ListItem::U64(3));
+/// assert!(ListItem::Int(1) < ListItem::Float(1.0));
+/// assert!(ListItem::Float(f64::INFINITY) < ListItem::Float(f64::NAN));
+/// assert!(ListItem::Float(f64::NAN) < ListItem::Str(String::new()));
+/// ```
+impl Ord for ListItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self.type_rank().cmp(&other.type_rank());
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        if let (Some(a), Some(b)) = (self.as_exact_number(), other.as_exact_number()) {
+            return a
+                .cmp(b)
+                .then_with(|| self.numeric_rank().cmp(&other.numeric_rank()));
+        }
+        match (self, other) {
+            (ListItem::Bool(a), ListItem::Bool(b)) => a.cmp(b),
+            (ListItem::Char(a), ListItem::Char(b)) => a.cmp(b),
+            (ListItem::Str(a), ListItem::Str(b)) => a.cmp(b),
+            (ListItem::List(a), ListItem::List(b)) => a.cmp(b),
+            (ListItem::Map(a), ListItem::Map(b)) => a.cmp(b),
+            (ListItem::Custom(a), ListItem::Custom(b)) => a
+                .type_name()
+                .cmp(b.type_name())
+                .then_with(|| self.as_any().type_id().cmp(&other.as_any().type_id()))
+                .then_with(|| {
+                    // Equal type ids make the downcast in `cmp_value` succeed.
+                    a.cmp_value(other.as_any()).unwrap()
+                }),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for ListItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
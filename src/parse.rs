@@ -0,0 +1,36 @@
+use crate::{ListError, ListItem};
+
+/// How deeply lists and maps may nest in every format, counting the outermost
+/// list as the first level. Writers refuse what the readers would reject.
+pub(crate) const MAX_DEPTH: usize = 128;
+
+/// Returns `ListError::TooDeep` for a list or map nested `depth` levels below
+/// the outermost list if that is past `MAX_DEPTH`.
+pub(crate) fn check_depth(depth: usize) -> Result<(), ListError> {
+    if depth == MAX_DEPTH {
+        return Err(ListError::TooDeep { limit: MAX_DEPTH });
+    }
+    Ok(())
+}
+
+/// Returns a `ListError::Parse` pointing at the byte offset `pos` of `input`.
+pub(crate) fn parse_error(input: &str, pos: usize) -> ListError {
+    let before = &input[..pos];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    ListError::Parse { line, column }
+}
+
+/// Picks `Int`, `I64`, `U64` or `I128`, whichever is the first to fit the value.
+pub(crate) fn narrowest_integer(value: i128) -> ListItem {
+    if let Ok(value) = i32::try_from(value) {
+        ListItem::Int(value)
+    } else if let Ok(value) = i64::try_from(value) {
+        ListItem::I64(value)
+    } else if let Ok(value) = u64::try_from(value) {
+        ListItem::U64(value)
+    } else {
+        ListItem::I128(value)
+    }
+}
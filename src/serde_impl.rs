@@ -0,0 +1,535 @@
+//! `Serialize` and `Deserialize` implementations, enabled by the `serde` feature.
+//!
+//! `List`, `ListItem` and `ListMap` serialize untagged, as the native values
+//! of the data format: integers, floats, strings, booleans, `null`, sequences
+//! and maps. This reads naturally in JSON or YAML, but does not survive a
+//! round-trip exactly: integers come back as `Int` when they fit in an `i32`
+//! and as the narrowest of `I64`, `U64`, `I128` and `U128` otherwise, and a
+//! format without a char type turns `Char` into `Str`.
+//!
+//! Wrapping a value in `Tagged` serializes every item as an externally tagged
+//! enum instead, such as `{"Int": 1}` or `{"Float": 1.0}` in JSON, which
+//! preserves the exact variant of each item. Human-readable formats such as
+//! JSON often have no representation for non-finite floats, so tagged mode
+//! writes them as the strings `"inf"`, `"-inf"` and `"NaN"` there, and any
+//! `NaN` other than `f64::NAN` as `"NaN(0x7ff8000000000001)"` with its bits.
+//! Untagged mode leaves them to the format, and serde_json writes them as
+//! `null`.
+//!
+//! Custom values have no serialized form and fail to serialize.
+
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{List, ListItem, ListItemKind, ListMap};
+
+/// Serializes and deserializes a `List`, `ListMap` or `ListItem` with the
+/// variant of every item preserved.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_list::{list, List, Tagged};
+///
+/// let list = list![1, 1.0, 'c', ()];
+///
+/// // Untagged, integers and floats become plain JSON numbers.
+/// let json = serde_json::to_string(&list).unwrap();
+/// assert_eq!(json, r#"[1,1.0,"c",null]"#);
+/// let back: List = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, list![1, 1.0, "c", ()]);
+///
+/// // Tagged, every item keeps its variant.
+/// let json = serde_json::to_string(&Tagged(&list)).unwrap();
+/// assert_eq!(json, r#"[{"Int":1},{"Float":1.0},{"Char":"c"},"Null"]"#);
+/// let Tagged(back): Tagged<List> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, list);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tagged<T>(pub T);
+
+fn custom_error<E: ser::Error>(item: &ListItem) -> E {
+    E::custom(format_args!(
+        "cannot serialize custom value of type `{}`",
+        item.type_name()
+    ))
+}
+
+impl Serialize for ListItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ListItem::Int(value) => serializer.serialize_i32(*value),
+            ListItem::I8(value) => serializer.serialize_i8(*value),
+            ListItem::I16(value) => serializer.serialize_i16(*value),
+            ListItem::I64(value) => serializer.serialize_i64(*value),
+            ListItem::I128(value) => serializer.serialize_i128(*value),
+            ListItem::Isize(value) => value.serialize(serializer),
+            ListItem::U8(value) => serializer.serialize_u8(*value),
+            ListItem::U16(value) => serializer.serialize_u16(*value),
+            ListItem::U32(value) => serializer.serialize_u32(*value),
+            ListItem::U64(value) => serializer.serialize_u64(*value),
+            ListItem::U128(value) => serializer.serialize_u128(*value),
+            ListItem::Usize(value) => value.serialize(serializer),
+            ListItem::Str(value) => serializer.serialize_str(value),
+            ListItem::Float(value) => serializer.serialize_f64(*value),
+            ListItem::Bool(value) => serializer.serialize_bool(*value),
+            ListItem::Char(value) => serializer.serialize_char(*value),
+            ListItem::Null => serializer.serialize_unit(),
+            ListItem::List(value) => value.serialize(serializer),
+            ListItem::Map(value) => value.serialize(serializer),
+            ListItem::Custom(_) => Err(custom_error(self)),
+        }
+    }
+}
+
+impl Serialize for List {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for ListMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+struct ItemVisitor;
+
+impl<'de> Visitor<'de> for ItemVisitor {
+    type Value = ListItem;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list item")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<ListItem, E> {
+        Ok(ListItem::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<ListItem, E> {
+        Ok(i32::try_from(value).map_or(ListItem::I64(value), ListItem::Int))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<ListItem, E> {
+        match i64::try_from(value) {
+            Ok(value) => self.visit_i64(value),
+            Err(_) => Ok(ListItem::I128(value)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<ListItem, E> {
+        match i64::try_from(value) {
+            Ok(value) => self.visit_i64(value),
+            Err(_) => Ok(ListItem::U64(value)),
+        }
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<ListItem, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Ok(ListItem::U128(value)),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<ListItem, E> {
+        Ok(ListItem::Float(value))
+    }
+
+    fn visit_char<E: de::Error>(self, value: char) -> Result<ListItem, E> {
+        Ok(ListItem::Char(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ListItem, E> {
+        Ok(ListItem::Str(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<ListItem, E> {
+        Ok(ListItem::Str(value))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<ListItem, E> {
+        Ok(ListItem::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<ListItem, E> {
+        Ok(ListItem::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<ListItem, D::Error> {
+        ListItem::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<ListItem, A::Error> {
+        ListVisitor::<ListItem>::new()
+            .visit_seq(seq)
+            .map(ListItem::List)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<ListItem, A::Error> {
+        MapVisitor::<ListItem>::new()
+            .visit_map(map)
+            .map(ListItem::Map)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ItemVisitor)
+    }
+}
+
+/// Collects a sequence into a `List`, deserializing every item as `T`.
+struct ListVisitor<T>(std::marker::PhantomData<T>);
+
+impl<T> ListVisitor<T> {
+    fn new() -> Self {
+        ListVisitor(std::marker::PhantomData)
+    }
+}
+
+impl<'de, T> Visitor<'de> for ListVisitor<T>
+where
+    T: Deserialize<'de> + Into<ListItem>,
+{
+    type Value = List;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of list items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<List, A::Error> {
+        let mut list = List::new();
+        while let Some(item) = seq.next_element::<T>()? {
+            list.insert(item);
+        }
+        Ok(list)
+    }
+}
+
+impl<'de> Deserialize<'de> for List {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor::<ListItem>::new())
+    }
+}
+
+/// Collects a map with string keys into a `ListMap`, deserializing every value as `T`.
+struct MapVisitor<T>(std::marker::PhantomData<T>);
+
+impl<T> MapVisitor<T> {
+    fn new() -> Self {
+        MapVisitor(std::marker::PhantomData)
+    }
+}
+
+impl<'de, T> Visitor<'de> for MapVisitor<T>
+where
+    T: Deserialize<'de> + Into<ListItem>,
+{
+    type Value = ListMap;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map with string keys")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ListMap, A::Error> {
+        let mut result = ListMap::new();
+        while let Some((key, value)) = map.next_entry::<String, T>()? {
+            result.insert(key, value);
+        }
+        Ok(result)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(MapVisitor::<ListItem>::new())
+    }
+}
+
+// Tagged serialization
+
+const VARIANTS: [&str; 20] = [
+    "Int", "I8", "I16", "I64", "I128", "Isize", "U8", "U16", "U32", "U64", "U128", "Usize", "Str",
+    "Float", "Bool", "Char", "Null", "List", "Map", "Custom",
+];
+
+impl Serialize for Tagged<&ListItem> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let item = self.0;
+        let kind = item.kind();
+        let index = kind as u32;
+        let name = kind.name();
+        match item {
+            ListItem::Int(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::I8(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::I16(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::I64(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::I128(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Isize(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::U8(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::U16(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::U32(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::U64(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::U128(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Usize(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Str(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Float(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, &TaggedFloat(*value))
+            }
+            ListItem::Bool(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Char(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, value)
+            }
+            ListItem::Null => serializer.serialize_unit_variant("ListItem", index, name),
+            ListItem::List(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, &Tagged(value))
+            }
+            ListItem::Map(value) => {
+                serializer.serialize_newtype_variant("ListItem", index, name, &Tagged(value))
+            }
+            ListItem::Custom(_) => Err(custom_error(item)),
+        }
+    }
+}
+
+impl Serialize for Tagged<ListItem> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tagged(&self.0).serialize(serializer)
+    }
+}
+
+impl Serialize for Tagged<&List> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0 {
+            seq.serialize_element(&Tagged(item))?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for Tagged<List> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tagged(&self.0).serialize(serializer)
+    }
+}
+
+impl Serialize for Tagged<&ListMap> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0.iter() {
+            map.serialize_entry(key, &Tagged(value))?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Tagged<ListMap> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tagged(&self.0).serialize(serializer)
+    }
+}
+
+/// A `Float` in tagged mode, spelling non-finite values as strings in
+/// human-readable formats.
+struct TaggedFloat(f64);
+
+impl Serialize for TaggedFloat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.0;
+        if value.is_finite() || !serializer.is_human_readable() {
+            serializer.serialize_f64(value)
+        } else if value.is_infinite() {
+            serializer.serialize_str(if value > 0.0 { "inf" } else { "-inf" })
+        } else if value.to_bits() == f64::NAN.to_bits() {
+            serializer.serialize_str("NaN")
+        } else {
+            serializer.collect_str(&format_args!("NaN({:#018x})", value.to_bits()))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TaggedFloat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FloatVisitor)
+        } else {
+            deserializer.deserialize_f64(FloatVisitor)
+        }
+    }
+}
+
+struct FloatVisitor;
+
+impl Visitor<'_> for FloatVisitor {
+    type Value = TaggedFloat;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a float, \"inf\", \"-inf\" or \"NaN\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<TaggedFloat, E> {
+        Ok(TaggedFloat(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<TaggedFloat, E> {
+        Ok(TaggedFloat(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<TaggedFloat, E> {
+        Ok(TaggedFloat(value as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<TaggedFloat, E> {
+        let float = match value {
+            "inf" => f64::INFINITY,
+            "-inf" => f64::NEG_INFINITY,
+            "NaN" => f64::NAN,
+            _ => value
+                .strip_prefix("NaN(0x")
+                .and_then(|bits| bits.strip_suffix(')'))
+                .and_then(|bits| u64::from_str_radix(bits, 16).ok())
+                .map(f64::from_bits)
+                .filter(|float| float.is_nan())
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))?,
+        };
+        Ok(TaggedFloat(float))
+    }
+}
+
+/// Deserializes a variant name or index into a `ListItemKind`.
+struct KindSeed;
+
+impl<'de> DeserializeSeed<'de> for KindSeed {
+    type Value = ListItemKind;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for KindSeed {
+    type Value = ListItemKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list item variant")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<ListItemKind, E> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|index| ListItemKind::ALL.get(index).copied())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ListItemKind, E> {
+        ListItemKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == value)
+            .ok_or_else(|| E::unknown_variant(value, &VARIANTS))
+    }
+}
+
+struct TaggedItemVisitor;
+
+impl<'de> Visitor<'de> for TaggedItemVisitor {
+    type Value = ListItem;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a tagged list item")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<ListItem, A::Error> {
+        let (kind, variant) = data.variant_seed(KindSeed)?;
+        match kind {
+            ListItemKind::Int => variant.newtype_variant().map(ListItem::Int),
+            ListItemKind::I8 => variant.newtype_variant().map(ListItem::I8),
+            ListItemKind::I16 => variant.newtype_variant().map(ListItem::I16),
+            ListItemKind::I64 => variant.newtype_variant().map(ListItem::I64),
+            ListItemKind::I128 => variant.newtype_variant().map(ListItem::I128),
+            ListItemKind::Isize => variant.newtype_variant().map(ListItem::Isize),
+            ListItemKind::U8 => variant.newtype_variant().map(ListItem::U8),
+            ListItemKind::U16 => variant.newtype_variant().map(ListItem::U16),
+            ListItemKind::U32 => variant.newtype_variant().map(ListItem::U32),
+            ListItemKind::U64 => variant.newtype_variant().map(ListItem::U64),
+            ListItemKind::U128 => variant.newtype_variant().map(ListItem::U128),
+            ListItemKind::Usize => variant.newtype_variant().map(ListItem::Usize),
+            ListItemKind::Str => variant.newtype_variant().map(ListItem::Str),
+            ListItemKind::Float => variant
+                .newtype_variant()
+                .map(|TaggedFloat(value)| ListItem::Float(value)),
+            ListItemKind::Bool => variant.newtype_variant().map(ListItem::Bool),
+            ListItemKind::Char => variant.newtype_variant().map(ListItem::Char),
+            ListItemKind::Null => variant.unit_variant().map(|()| ListItem::Null),
+            ListItemKind::List => variant
+                .newtype_variant::<Tagged<List>>()
+                .map(|Tagged(list)| ListItem::List(list)),
+            ListItemKind::Map => variant
+                .newtype_variant::<Tagged<ListMap>>()
+                .map(|Tagged(map)| ListItem::Map(map)),
+            ListItemKind::Custom => Err(de::Error::custom("cannot deserialize custom values")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Tagged<ListItem> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_enum("ListItem", &VARIANTS, TaggedItemVisitor)
+            .map(Tagged)
+    }
+}
+
+impl From<Tagged<ListItem>> for ListItem {
+    fn from(Tagged(item): Tagged<ListItem>) -> Self {
+        item
+    }
+}
+
+impl<'de> Deserialize<'de> for Tagged<List> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_seq(ListVisitor::<Tagged<ListItem>>::new())
+            .map(Tagged)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tagged<ListMap> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_map(MapVisitor::<Tagged<ListItem>>::new())
+            .map(Tagged)
+    }
+}
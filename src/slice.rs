@@ -0,0 +1,132 @@
+use std::fmt;
+use std::ops::{Index, Range, RangeBounds};
+
+use crate::{List, ListError, ListItem, ListIter};
+
+/// A borrowed view into a contiguous range of a `List`, created by `List::slice`.
+///
+/// Indices passed to the view are relative to the start of the range.
+#[derive(Clone, Copy)]
+pub struct ListSlice<'a> {
+    list: &'a List,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> ListSlice<'a> {
+    pub(crate) fn new(list: &'a List, range: Range<usize>) -> Self {
+        ListSlice {
+            list,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the number of items in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, 2, 3, 4];
+    /// assert_eq!(list.slice(1..3).unwrap().len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Retrieves a reference to the item at the specified index of the view if the type matches.
+    ///
+    /// Returns `None` if the index is out of bounds or the type doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, "two", 3];
+    /// let view = list.slice(1..).unwrap();
+    /// assert_eq!(view.get::<String>(0), Some(&"two".to_string()));
+    /// assert_eq!(view.get::<i32>(2), None);
+    /// ```
+    pub fn get<T: 'static>(&self, index: usize) -> Option<&'a T> {
+        if index < self.len() {
+            self.list.get::<T>(self.start + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the items in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = rusty_list::list![1, 2, 3, 4];
+    /// let view = list.slice(..2).unwrap();
+    /// assert_eq!(view.iter().rev().map(|item| item.to_string()).collect::<Vec<_>>(), ["2", "1"]);
+    /// ```
+    pub fn iter(&self) -> ListIter<'a> {
+        ListIter {
+            list: self.list,
+            index: self.start,
+            end: self.end,
+        }
+    }
+
+    /// Returns a narrower view, with the range relative to this view.
+    ///
+    /// Returns `ListError::InvalidRange` if the range is reversed or extends
+    /// past the end of the view.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<ListSlice<'a>, ListError> {
+        let range = crate::check_range(range, self.len())?;
+        Ok(ListSlice::new(
+            self.list,
+            self.start + range.start..self.start + range.end,
+        ))
+    }
+
+    /// Copies the items of the view into a new `List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// let list = list![1, 2, 3];
+    /// assert_eq!(list.slice(1..).unwrap().to_list(), list![2, 3]);
+    /// ```
+    pub fn to_list(&self) -> List {
+        self.iter().cloned().collect()
+    }
+}
+
+impl Index<usize> for ListSlice<'_> {
+    type Output = ListItem;
+
+    fn index(&self, index: usize) -> &ListItem {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        &self.list[self.start + index]
+    }
+}
+
+impl<'a> IntoIterator for ListSlice<'a> {
+    type Item = &'a ListItem;
+    type IntoIter = ListIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Implementation of Debug for ListSlice
+impl fmt::Debug for ListSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt;
+
+use crate::{List, ListError, ListItem};
+
+/// The result of a numeric aggregation over a `List`.
+///
+/// Aggregations stay in the integer domain while every numeric item is an
+/// integer, and switch to `Float` as soon as one `Float` item takes part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    /// Returns the number as an `f64`, rounding large integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(rusty_list::Number::Int(3).as_f64(), 3.0);
+    /// ```
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Number::Int(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Int(val) => write!(f, "{val}"),
+            Number::Float(val) => write!(f, "{val}"),
+        }
+    }
+}
+
+impl ListItem {
+    /// Returns the item as an `i128` if it is an integer.
+    ///
+    /// `U128` values above `i128::MAX` are returned unchanged as the error.
+    pub(crate) fn integer_value(&self) -> Option<Result<i128, u128>> {
+        let value = match *self {
+            ListItem::Int(value) => value.into(),
+            ListItem::I8(value) => value.into(),
+            ListItem::I16(value) => value.into(),
+            ListItem::I64(value) => value.into(),
+            ListItem::I128(value) => value,
+            ListItem::Isize(value) => value as i128,
+            ListItem::U8(value) => value.into(),
+            ListItem::U16(value) => value.into(),
+            ListItem::U32(value) => value.into(),
+            ListItem::U64(value) => value.into(),
+            ListItem::Usize(value) => value as i128,
+            ListItem::U128(value) => return Some(i128::try_from(value).map_err(|_| value)),
+            _ => return None,
+        };
+        Some(Ok(value))
+    }
+
+    /// Returns the item as an `f64` if it is an integer or a `Float`.
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            ListItem::Float(value) => Some(*value),
+            _ => match self.integer_value()? {
+                Ok(value) => Some(value as f64),
+                Err(value) => Some(value as f64),
+            },
+        }
+    }
+}
+
+/// Numeric aggregations.
+///
+/// Every integer variant and `Float` count as numbers. All other items,
+/// including `Bool` and `Null`, are skipped.
+impl List {
+    /// Returns the sum of the numeric items.
+    ///
+    /// The sum is exact and returned as `Number::Int` if there are no `Float`
+    /// items, otherwise it is computed in floating point. Returns
+    /// `ListError::Overflow` if an integer sum does not fit in an `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListError, Number};
+    ///
+    /// assert_eq!(list![1, 2u8, "skipped", 3i64].sum(), Ok(Number::Int(6)));
+    /// assert_eq!(list![1, 0.5].sum(), Ok(Number::Float(1.5)));
+    /// assert!(matches!(
+    ///     list![i128::MAX, 1].sum(),
+    ///     Err(ListError::Overflow { .. })
+    /// ));
+    /// ```
+    pub fn sum(&self) -> Result<Number, ListError> {
+        if self.has_float() {
+            return Ok(Number::Float(self.numbers().sum()));
+        }
+        let mut total: i128 = 0;
+        for value in self.integers("sum") {
+            total = total
+                .checked_add(value?)
+                .ok_or(ListError::Overflow { operation: "sum" })?;
+        }
+        Ok(Number::Int(total))
+    }
+
+    /// Returns the product of the numeric items, `Number::Int(1)` if there are none.
+    ///
+    /// Follows the same rules as `List::sum`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, Number};
+    ///
+    /// assert_eq!(list![2, 3u64, 4].product(), Ok(Number::Int(24)));
+    /// assert_eq!(list![2, 0.5].product(), Ok(Number::Float(1.0)));
+    /// assert!(list![u64::MAX, u64::MAX, u64::MAX].product().is_err());
+    /// ```
+    pub fn product(&self) -> Result<Number, ListError> {
+        if self.has_float() {
+            return Ok(Number::Float(self.numbers().product()));
+        }
+        let mut total: i128 = 1;
+        for value in self.integers("product") {
+            total = total.checked_mul(value?).ok_or(ListError::Overflow {
+                operation: "product",
+            })?;
+        }
+        Ok(Number::Int(total))
+    }
+
+    /// Returns the arithmetic mean of the numeric items, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// assert_eq!(list![1, 2.0, "x", 6].mean(), Some(3.0));
+    /// assert_eq!(list![f64::MAX, f64::MAX].mean(), Some(f64::MAX));
+    /// assert_eq!(list!["x"].mean(), None);
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        // Updated incrementally like `variance`, so that the mean of large
+        // values does not overflow the way their sum would.
+        let mut count = 0usize;
+        let mut mean = 0.0;
+        for value in self.numbers() {
+            count += 1;
+            mean += (value - mean) / count as f64;
+        }
+        (count > 0).then_some(mean)
+    }
+
+    /// Returns the smallest numeric item, or `None` if there are none.
+    ///
+    /// Items are compared by value across variants using the total order of
+    /// `ListItem`. Named apart from `Ord::min`, which compares whole lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItem};
+    ///
+    /// let list = list!["a", 3, -1.5, 2u8];
+    /// assert_eq!(list.min_number(), Some(&ListItem::Float(-1.5)));
+    /// ```
+    pub fn min_number(&self) -> Option<&ListItem> {
+        self.items
+            .iter()
+            .filter(|item| item.as_f64().is_some())
+            .min()
+    }
+
+    /// Returns the largest numeric item, or `None` if there are none.
+    ///
+    /// Items are compared by value across variants using the total order of
+    /// `ListItem`, so a `NaN` is larger than every other number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::{list, ListItem};
+    ///
+    /// let list = list!["z", 3, -1.5, 2u8];
+    /// assert_eq!(list.max_number(), Some(&ListItem::Int(3)));
+    /// ```
+    pub fn max_number(&self) -> Option<&ListItem> {
+        self.items
+            .iter()
+            .filter(|item| item.as_f64().is_some())
+            .max()
+    }
+
+    /// Returns the population variance of the numeric items, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// assert_eq!(list![2, 4, 4, 4, 5, 5, 7, 9].variance(), Some(4.0));
+    /// ```
+    pub fn variance(&self) -> Option<f64> {
+        // Welford's online algorithm, which avoids catastrophic cancellation.
+        let mut count = 0usize;
+        let mut mean = 0.0;
+        let mut squares = 0.0;
+        for value in self.numbers() {
+            count += 1;
+            let delta = value - mean;
+            mean += delta / count as f64;
+            squares += delta * (value - mean);
+        }
+        (count > 0).then(|| squares / count as f64)
+    }
+
+    /// Returns the population standard deviation of the numeric items, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// assert_eq!(list![2, 4, 4, 4, 5, 5, 7, 9].stddev(), Some(2.0));
+    /// ```
+    pub fn stddev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the median of the numeric items, or `None` if there are none.
+    ///
+    /// With an even number of items this is the mean of the two middle ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_list::list;
+    ///
+    /// assert_eq!(list![5, 1, 3.0].median(), Some(3.0));
+    /// assert_eq!(list![4, 1, 3, 2].median(), Some(2.5));
+    /// assert_eq!(list![f64::MAX, f64::MAX].median(), Some(f64::MAX));
+    /// ```
+    pub fn median(&self) -> Option<f64> {
+        let mut values: Vec<f64> = self.numbers().collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(f64::total_cmp);
+        let middle = values.len() / 2;
+        if values.len().is_multiple_of(2) {
+            Some(values[middle - 1].midpoint(values[middle]))
+        } else {
+            Some(values[middle])
+        }
+    }
+
+    fn has_float(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(item, ListItem::Float(_)))
+    }
+
+    fn numbers(&self) -> impl Iterator<Item = f64> + '_ {
+        self.items.iter().filter_map(ListItem::as_f64)
+    }
+
+    fn integers(
+        &self,
+        operation: &'static str,
+    ) -> impl Iterator<Item = Result<i128, ListError>> + '_ {
+        self.items
+            .iter()
+            .filter_map(ListItem::integer_value)
+            .map(move |value| value.map_err(|_| ListError::Overflow { operation }))
+    }
+}
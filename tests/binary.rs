@@ -0,0 +1,178 @@
+//! Golden-file tests for the binary format described in docs/binary-format.md.
+//!
+//! Each case encodes a list and compares the bytes with a checked-in file,
+//! then decodes the file back. Run with `UPDATE_GOLDEN=1` to rewrite the files
+//! after an intentional format change, and bump the format version with it.
+
+use std::fs;
+use std::path::PathBuf;
+
+use rusty_list::{list, List, ListError, ListMap};
+
+mod common;
+use common::nested;
+
+fn golden(name: &str, list: &List) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+    let mut bytes = Vec::new();
+    list.encode(&mut bytes).unwrap();
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &bytes).unwrap();
+    }
+    let expected = fs::read(&path).unwrap();
+    assert_eq!(bytes, expected, "encoding of {name} changed");
+    assert_eq!(List::decode(&mut expected.as_slice()).as_ref(), Ok(list));
+}
+
+fn encode(list: &List) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    list.encode(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn golden_empty() {
+    golden("empty.bin", &List::new());
+}
+
+#[test]
+fn golden_example() {
+    golden("example.bin", &list![1, -1, "a"]);
+}
+
+#[test]
+fn golden_scalars() {
+    golden(
+        "scalars.bin",
+        &list![
+            (),
+            true,
+            false,
+            i32::MIN,
+            i8::MAX,
+            i16::MIN,
+            i64::MAX,
+            i128::MIN,
+            -7isize,
+            u8::MAX,
+            u16::MAX,
+            u32::MAX,
+            u64::MAX,
+            u128::MAX,
+            7usize,
+            -0.0,
+            f64::NAN,
+            f64::INFINITY,
+            1.5e-300,
+            'é',
+            '😀',
+        ],
+    );
+}
+
+#[test]
+fn golden_nested() {
+    let mut map = ListMap::new();
+    map.insert("name", "Grüße, \"world\"");
+    map.insert("tags", list!["a", "b"]);
+    map.insert("empty", ListMap::new());
+    golden("nested.bin", &list![list![list![]], map, ""]);
+}
+
+#[test]
+fn nesting_limit() {
+    let list = nested(128);
+    let bytes = encode(&list);
+    assert_eq!(List::decode(&mut bytes.as_slice()), Ok(list));
+
+    let mut map = ListMap::new();
+    map.insert("inner", nested(127));
+    let list = list![map];
+    let mut bytes = Vec::new();
+    assert_eq!(
+        list.encode(&mut bytes),
+        Err(ListError::TooDeep { limit: 128 })
+    );
+
+    let list = nested(129);
+    assert_eq!(
+        list.encode(&mut bytes),
+        Err(ListError::TooDeep { limit: 128 })
+    );
+    assert!(bytes.is_empty());
+
+    // Input from another encoder that nests 129 levels deep. Decoding fails
+    // before reaching the checksum, so it can be left zeroed.
+    let mut bytes = b"RLST\x01\x01".to_vec();
+    for _ in 0..127 {
+        bytes.extend_from_slice(&[0x11, 0x01]);
+    }
+    bytes.extend_from_slice(&[0x11, 0x00, 0, 0, 0, 0]);
+    assert_eq!(
+        List::decode(&mut bytes.as_slice()),
+        Err(ListError::Decode {
+            offset: bytes.len() - 5,
+            reason: "nesting too deep"
+        })
+    );
+}
+
+#[test]
+fn decode_rejects_truncated_input() {
+    let bytes = encode(&list![1, "abc"]);
+    for len in 0..bytes.len() {
+        assert!(matches!(
+            List::decode(&mut &bytes[..len]),
+            Err(ListError::Decode {
+                reason: "unexpected end of input",
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn decode_reports_offsets() {
+    let mut bytes = encode(&list![1]);
+    bytes[0] = b'X';
+    assert_eq!(
+        List::decode(&mut bytes.as_slice()),
+        Err(ListError::Decode {
+            offset: 0,
+            reason: "bad magic number"
+        })
+    );
+
+    let mut bytes = encode(&list![1]);
+    bytes[4] = 2;
+    assert_eq!(
+        List::decode(&mut bytes.as_slice()),
+        Err(ListError::Decode {
+            offset: 4,
+            reason: "unsupported version"
+        })
+    );
+
+    let mut bytes = encode(&list![1]);
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xFF;
+    assert_eq!(
+        List::decode(&mut bytes.as_slice()),
+        Err(ListError::Decode {
+            offset: 8,
+            reason: "checksum mismatch"
+        })
+    );
+}
+
+#[test]
+fn decode_reads_concatenated_lists() {
+    let mut bytes = encode(&list![1]);
+    bytes.extend(encode(&list!["two"]));
+    let mut reader = bytes.as_slice();
+    assert_eq!(List::decode(&mut reader), Ok(list![1]));
+    assert_eq!(List::decode(&mut reader), Ok(list!["two"]));
+    assert!(reader.is_empty());
+}
@@ -0,0 +1,12 @@
+//! Fixtures shared by the integration tests.
+
+use rusty_list::{list, List};
+
+/// Builds a list nested `levels` deep, the outermost list included.
+pub fn nested(levels: usize) -> List {
+    let mut list = List::new();
+    for _ in 1..levels {
+        list = list![list];
+    }
+    list
+}
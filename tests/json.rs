@@ -0,0 +1,97 @@
+//! Tests for `List::to_json` and `List::from_json` beyond the doctests.
+
+use std::fmt;
+
+use rusty_list::{list, List, ListError, ListMap, ListValue};
+
+mod common;
+use common::nested;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Point(i32, i32);
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl ListValue for Point {}
+
+fn parse_error(line: usize, column: usize) -> Result<List, ListError> {
+    Err(ListError::Parse { line, column })
+}
+
+#[test]
+fn nesting_limit() {
+    let list = nested(128);
+    let json = list.to_json().unwrap();
+    assert_eq!(List::from_json(&json), Ok(list));
+
+    let list = nested(129);
+    assert_eq!(list.to_json(), Err(ListError::TooDeep { limit: 128 }));
+
+    let mut map = ListMap::new();
+    map.insert("deep", nested(127));
+    let list = list![map];
+    assert_eq!(list.to_json(), Err(ListError::TooDeep { limit: 128 }));
+
+    let json = format!("{}{}", "[".repeat(129), "]".repeat(129));
+    assert_eq!(List::from_json(&json), parse_error(1, 129));
+    let json = format!("{}{{\"k\":{}", "[".repeat(128), "]".repeat(128));
+    assert_eq!(List::from_json(&json), parse_error(1, 129));
+}
+
+#[test]
+fn custom_values_fail_to_serialize() {
+    let list = list![1, list![Point(1, 2)]];
+    assert!(matches!(
+        list.to_json(),
+        Err(ListError::Unsupported {
+            operation: "JSON",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn surrogate_pairs() {
+    let list = List::from_json(r#"["😀", "é"]"#).unwrap();
+    assert_eq!(list, list!["😀", "é"]);
+
+    // A lone or reversed surrogate is reported at the start of its escape.
+    assert_eq!(List::from_json(r#"["\ud83d"]"#), parse_error(1, 3));
+    assert_eq!(List::from_json(r#"["\ud83dx"]"#), parse_error(1, 3));
+    assert_eq!(List::from_json(r#"["\ud83dA"]"#), parse_error(1, 3));
+    assert_eq!(List::from_json(r#"["\ude00\ud83d"]"#), parse_error(1, 3));
+    assert_eq!(List::from_json(r#"["\ud83g"]"#), parse_error(1, 5));
+}
+
+#[test]
+fn control_characters() {
+    let list = list!["\0\u{1}\u{8}\t\n\u{c}\r\u{1f}\u{7f}", '\u{1b}'];
+    let json = list.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"["\u0000\u0001\b\t\n\f\r\u001f"#.to_string() + "\u{7f}" + r#"","\u001b"]"#
+    );
+    // Characters come back as one-character strings.
+    assert_eq!(
+        List::from_json(&json),
+        Ok(list!["\0\u{1}\u{8}\t\n\u{c}\r\u{1f}\u{7f}", "\u{1b}"])
+    );
+
+    // Control characters must be escaped inside strings.
+    assert_eq!(List::from_json("[\"a\tb\"]"), parse_error(1, 4));
+    assert_eq!(List::from_json("[\"a\nb\"]"), parse_error(1, 4));
+}
+
+#[test]
+fn trailing_garbage() {
+    assert_eq!(List::from_json("[1] \n"), Ok(list![1]));
+    assert_eq!(List::from_json("[1] x"), parse_error(1, 5));
+    assert_eq!(List::from_json("[1]\n[2]"), parse_error(2, 1));
+    assert_eq!(List::from_json("[1],"), parse_error(1, 4));
+    assert_eq!(List::from_json("[1, 2] // comment"), parse_error(1, 8));
+    assert_eq!(List::from_json("[1]]"), parse_error(1, 4));
+}
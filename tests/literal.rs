@@ -0,0 +1,97 @@
+//! Round-trip tests for `List::to_literal` and `str::parse::<List>()`.
+
+use rusty_list::{list, List, ListError, ListMap};
+
+mod common;
+use common::nested;
+
+fn round_trip(list: &List) -> List {
+    list.to_literal().unwrap().parse().unwrap()
+}
+
+#[test]
+fn integer_extremes() {
+    let list = list![
+        i32::MIN,
+        i32::MAX,
+        i8::MIN,
+        i8::MAX,
+        i16::MIN,
+        i16::MAX,
+        i64::MIN,
+        i64::MAX,
+        i128::MIN,
+        i128::MAX,
+        isize::MIN,
+        isize::MAX,
+        u8::MIN,
+        u8::MAX,
+        u16::MIN,
+        u16::MAX,
+        u32::MIN,
+        u32::MAX,
+        u64::MIN,
+        u64::MAX,
+        u128::MIN,
+        u128::MAX,
+        usize::MIN,
+        usize::MAX,
+    ];
+    assert_eq!(round_trip(&list), list);
+}
+
+#[test]
+fn special_floats() {
+    let payload = f64::from_bits(0x7ff8_0000_0000_0001);
+    let list = list![-0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, payload];
+    let literal = list.to_literal().unwrap();
+    assert_eq!(literal, "[-0.0, inf, -inf, NaN, NaN(0x7ff8000000000001)]");
+
+    // `ListItem` compares floats by their bits, so this also tells `0.0` from
+    // `-0.0` and checks the NaN payload.
+    assert_eq!(literal.parse::<List>(), Ok(list));
+}
+
+#[test]
+fn escaped_text() {
+    let list = list![
+        "quote \" apostrophe ' backslash \\",
+        "\0\u{1}\t\n\r\u{1b}\u{7f}\u{85}",
+        '"',
+        '\'',
+        '\\',
+        '\0',
+        '\u{1}',
+        '\n',
+        '\u{7f}',
+    ];
+    assert_eq!(round_trip(&list), list);
+
+    let mut map = ListMap::new();
+    map.insert("key \"with\"\nescapes", '\t');
+    let list = list![map];
+    assert_eq!(round_trip(&list), list);
+}
+
+#[test]
+fn nesting_limit() {
+    let list = nested(128);
+    assert_eq!(round_trip(&list), list);
+
+    let list = nested(129);
+    assert_eq!(list.to_literal(), Err(ListError::TooDeep { limit: 128 }));
+
+    let mut map = ListMap::new();
+    map.insert("deep", nested(127));
+    let list = list![map];
+    assert_eq!(list.to_literal(), Err(ListError::TooDeep { limit: 128 }));
+
+    let literal = format!("{}{}", "[".repeat(129), "]".repeat(129));
+    assert_eq!(
+        literal.parse::<List>(),
+        Err(ListError::Parse {
+            line: 1,
+            column: 129
+        })
+    );
+}
@@ -0,0 +1,156 @@
+//! Tests for the `Serialize` and `Deserialize` implementations of the `serde` feature.
+#![cfg(feature = "serde")]
+
+use std::fmt;
+
+use rusty_list::{list, List, ListItem, ListMap, ListValue, Tagged};
+use serde::de::value::{
+    Error, I128Deserializer, MapAccessDeserializer, MapDeserializer, U128Deserializer,
+    U32Deserializer,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Point(i32, i32);
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl ListValue for Point {}
+
+fn sample_map() -> ListMap {
+    let mut map = ListMap::new();
+    map.insert("name", "rusty");
+    map.insert("size", 3u8);
+    map.insert("tags", list!['a', 1.5]);
+    map
+}
+
+fn tagged_round_trip(list: &List) -> List {
+    let json = serde_json::to_string(&Tagged(list)).unwrap();
+    let Tagged(back) = serde_json::from_str(&json).unwrap();
+    back
+}
+
+#[test]
+fn untagged_map() {
+    let json = serde_json::to_string(&sample_map()).unwrap();
+    assert_eq!(json, r#"{"name":"rusty","size":3,"tags":["a",1.5]}"#);
+
+    let back: ListMap = serde_json::from_str(&json).unwrap();
+    let mut expected = ListMap::new();
+    expected.insert("name", "rusty");
+    expected.insert("size", 3);
+    expected.insert("tags", list!["a", 1.5]);
+    assert_eq!(back, expected);
+}
+
+#[test]
+fn untagged_integer_narrowing() {
+    let back: List = serde_json::from_str("[1, -3000000000, 18446744073709551615]").unwrap();
+    assert_eq!(back, list![1, -3_000_000_000i64, u64::MAX]);
+
+    let item = ListItem::deserialize(I128Deserializer::<Error>::new(i128::MIN)).unwrap();
+    assert_eq!(item, ListItem::I128(i128::MIN));
+    let item = ListItem::deserialize(I128Deserializer::<Error>::new(-5)).unwrap();
+    assert_eq!(item, ListItem::Int(-5));
+    let item = ListItem::deserialize(U128Deserializer::<Error>::new(u128::MAX)).unwrap();
+    assert_eq!(item, ListItem::U128(u128::MAX));
+    let item = ListItem::deserialize(U128Deserializer::<Error>::new(u64::MAX.into())).unwrap();
+    assert_eq!(item, ListItem::U64(u64::MAX));
+}
+
+#[test]
+fn tagged_nested_lists_and_maps() {
+    let list = list![
+        list![1u16, list![()]],
+        sample_map(),
+        ListMap::new(),
+        list![]
+    ];
+    let json = serde_json::to_string(&Tagged(&list)).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"[{"List":[{"U16":1},{"List":["Null"]}]},"#,
+            r#"{"Map":{"name":{"Str":"rusty"},"size":{"U8":3},"tags":{"List":[{"Char":"a"},{"Float":1.5}]}}},"#,
+            r#"{"Map":{}},{"List":[]}]"#
+        )
+    );
+    assert_eq!(tagged_round_trip(&list), list);
+
+    let json = serde_json::to_string(&Tagged(sample_map())).unwrap();
+    let Tagged(map): Tagged<ListMap> = serde_json::from_str(&json).unwrap();
+    assert_eq!(map, sample_map());
+}
+
+#[test]
+fn tagged_integer_extremes() {
+    let list = list![
+        i8::MIN,
+        i16::MIN,
+        i32::MIN,
+        i64::MIN,
+        i128::MIN,
+        isize::MIN,
+        u8::MAX,
+        u16::MAX,
+        u32::MAX,
+        u64::MAX,
+        u128::MAX,
+        usize::MAX,
+    ];
+    assert_eq!(tagged_round_trip(&list), list);
+}
+
+#[test]
+fn tagged_non_finite_floats() {
+    let payload = f64::from_bits(0x7ff8_0000_0000_0001);
+    let list = list![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0, payload];
+    let json = serde_json::to_string(&Tagged(&list)).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"Float":"NaN"},{"Float":"inf"},{"Float":"-inf"},{"Float":-0.0},{"Float":"NaN(0x7ff8000000000001)"}]"#
+    );
+    assert_eq!(tagged_round_trip(&list), list);
+
+    let result: Result<Tagged<List>, _> = serde_json::from_str(r#"[{"Float":"NaN(0x1)"}]"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn custom_values_fail_to_serialize() {
+    let list = list![1, Point(1, 2)];
+    let error = serde_json::to_string(&list).unwrap_err();
+    assert!(error.to_string().contains("cannot serialize custom value"));
+    assert!(serde_json::to_string(&Tagged(&list)).is_err());
+
+    let result: Result<Tagged<ListItem>, _> = serde_json::from_str(r#"{"Custom":1}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn tagged_variant_by_index() {
+    let Tagged(item) = Tagged::<ListItem>::deserialize(U32Deserializer::<Error>::new(16)).unwrap();
+    assert_eq!(item, ListItem::Null);
+
+    let result = Tagged::<ListItem>::deserialize(U32Deserializer::<Error>::new(20));
+    assert!(result.is_err());
+
+    // A single-entry map keyed by the variant index, as formats without
+    // variant names deliver it.
+    let entries = MapDeserializer::<_, Error>::new([(9u32, 7u64)].into_iter());
+    let Tagged(item) =
+        Tagged::<ListItem>::deserialize(MapAccessDeserializer::new(entries)).unwrap();
+    assert_eq!(item, ListItem::U64(7));
+}
+
+#[test]
+fn tagged_unknown_variant() {
+    let result: Result<Tagged<ListItem>, _> = serde_json::from_str(r#"{"Int8":1}"#);
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("unknown variant `Int8`"), "{error}");
+}